@@ -0,0 +1,93 @@
+//! Interpolate the X and Y Virtual grids at build time and write them to `$OUT_DIR/data.rs`, included by src/data.rs.
+//! The grids are regenerated whenever the control points, the geometry, the method or the interpolation code change:
+//! ```text
+//! NNINTERPOLATION_POINTS=data/control_points.csv      # Control points CSV [default: built-in control points]
+//! NNINTERPOLATION_GEOMETRY=data/pinetime.geometry     # Display geometry [default: constants.rs]
+//! NNINTERPOLATION_METHOD=nn                           # Any `--method` of the CLI, e.g. sibson [default: nn]
+//! NNINTERPOLATION_PARAMETERS=data/default.parameters  # Parameters of the method [default: constants.rs]
+//! ```
+//! The interpolation modules are shared with the library, since a build script can't link the crate that it builds.
+#![allow(dead_code)]
+extern crate cgmath;
+extern crate nalgebra;
+extern crate serde;
+#[macro_use]
+extern crate serde_derive;
+extern crate spade;
+
+#[path = "src/constants.rs"]
+mod constants;
+#[path = "src/control_points.rs"]
+mod control_points;
+#[path = "src/delaunay_creation.rs"]
+mod delaunay_creation;
+#[path = "src/geometry.rs"]
+mod geometry;
+#[path = "src/interpolation.rs"]
+mod interpolation;
+#[path = "src/lattice.rs"]
+mod lattice;
+#[path = "src/parameters.rs"]
+mod parameters;
+
+use std::env;
+use std::fs::File;
+use std::io::{BufWriter, Write};
+use std::path::Path;
+
+use crate::control_points::load_control_points;
+use crate::delaunay_creation::{builtin_control_points, triangulation_from_control_points};
+use crate::geometry::DisplayGeometry;
+use crate::interpolation::Grid;
+use crate::parameters::MethodParameters;
+use cgmath as cg;
+use nalgebra as na;
+
+/// Needed by interpolation.rs, same as in lib.rs
+pub fn cg_vec_to_na(vec: cg::Vector3<f64>) -> na::Point3<f32> {
+    na::Point3::new(vec.x as f32, vec.y as f32, vec.z as f32)
+}
+
+fn main() {
+    for source in &["build.rs", "src/constants.rs", "src/control_points.rs", "src/delaunay_creation.rs",
+                    "src/geometry.rs", "src/interpolation.rs", "src/lattice.rs", "src/parameters.rs"] {
+        println!("cargo:rerun-if-changed={}", source);
+    }
+    for name in &["NNINTERPOLATION_POINTS", "NNINTERPOLATION_GEOMETRY", "NNINTERPOLATION_METHOD", "NNINTERPOLATION_PARAMETERS"] {
+        println!("cargo:rerun-if-env-changed={}", name);
+    }
+
+    let points = match env::var("NNINTERPOLATION_POINTS") {
+        Ok(path) => {
+            println!("cargo:rerun-if-changed={}", path);
+            load_control_points(&path).unwrap_or_else(|err| panic!("{}: {}", path, err))
+        }
+        Err(_) => builtin_control_points(),
+    };
+    let geometry = match env::var("NNINTERPOLATION_GEOMETRY") {
+        Ok(path) => {
+            println!("cargo:rerun-if-changed={}", path);
+            DisplayGeometry::load(&path).unwrap_or_else(|err| panic!("{}: {}", path, err))
+        }
+        Err(_) => DisplayGeometry::default(),
+    };
+    let parameters = match env::var("NNINTERPOLATION_PARAMETERS") {
+        Ok(path) => {
+            println!("cargo:rerun-if-changed={}", path);
+            MethodParameters::load(&path).unwrap_or_else(|err| panic!("{}: {}", path, err))
+        }
+        Err(_) => MethodParameters::default(),
+    };
+    let name = env::var("NNINTERPOLATION_METHOD").unwrap_or_else(|_| "nn".to_string());
+
+    let delaunay = triangulation_from_control_points(&points);
+    let method = parameters.method(&name, &delaunay)
+        .unwrap_or_else(|err| panic!("NNINTERPOLATION_METHOD={}: {}", name, err));
+    let out_path = Path::new(&env::var("OUT_DIR").unwrap()).join("data.rs");
+    let mut out = BufWriter::new(File::create(&out_path).unwrap());
+    writeln!(out, "// Generated by build.rs from {} control points ({}), do not edit",
+             points.len(), env::var("NNINTERPOLATION_POINTS").unwrap_or_else(|_| "built-in".to_string())).unwrap();
+    writeln!(out, "// {:?}\n", geometry).unwrap();
+    let grid = Grid::from_delaunay_interpolation(&*method, &delaunay, &geometry);
+    grid.write_statics(&mut out).unwrap();
+}
@@ -8,7 +8,7 @@
 
 //! Constants for interpolating CHIP-8's Emulator Screen to PineTime Display.
 //! Since the X and Y axes are symmetric, we only compute one quadrant here (X >= 0, Y >= 0)
-use cgmath::Vector2;
+//! These are the defaults for `DisplayGeometry`, which may be changed at runtime.
 
 /// Range of Physical (x,y) coordinates, based on PineTime screen resolution
 pub const X_PHYSICAL_MIN: f64 = 0.0;
@@ -30,41 +30,30 @@ pub const Y_PHYSICAL_INCREMENT: f64 = 1.0;  //  i.e. 0, 1, 2, ...
 pub const X_VIRTUAL_INCREMENT: f64 = 1.0;  //  i.e. 0, 1, 2, ...
 pub const Y_VIRTUAL_INCREMENT: f64 = 1.0;  //  i.e. 0, 1, 2, ...
 
-/// How many divisions in the Physical X and Y axes to be interpolated
-pub const X_PHYSICAL_SUBDIVISIONS: usize = ((X_PHYSICAL_MAX - X_PHYSICAL_MIN) / X_PHYSICAL_INCREMENT) as usize;
-pub const Y_PHYSICAL_SUBDIVISIONS: usize = ((Y_PHYSICAL_MAX - Y_PHYSICAL_MIN) / Y_PHYSICAL_INCREMENT) as usize;
+/// The interpolated Physical grid extends a little beyond the PineTime Display
+pub const PHYSICAL_MARGIN: f64 = 1.05;
 
-/// How many divisions in the Virtual X and Y axes to be computed for Bounding Box
-pub const X_VIRTUAL_SUBDIVISIONS: usize = ((X_VIRTUAL_MAX - X_VIRTUAL_MIN) / X_VIRTUAL_INCREMENT) as usize;
-pub const Y_VIRTUAL_SUBDIVISIONS: usize = ((Y_VIRTUAL_MAX - Y_VIRTUAL_MIN) / Y_VIRTUAL_INCREMENT) as usize;
+/// Radius of the sphere in Physical pixels, and the number of control points per axis, for `SphericalProjection`
+pub const SPHERE_RADIUS: f64 = 120.0;
+pub const X_LATTICE: usize = 4;
+pub const Y_LATTICE: usize = 4;
 
-/// Width and height of the PineTime Display
-pub const PHYSICAL_WIDTH: f64 = (X_PHYSICAL_MAX - X_PHYSICAL_MIN) * 1.05;
-pub const PHYSICAL_HEIGHT: f64 = (Y_PHYSICAL_MAX - Y_PHYSICAL_MIN) * 1.05;
+//  Defaults for the interpolation methods, which may be changed at runtime with `MethodParameters`
 
-/// Width and height of the CHIP-8 Emulator Display
-pub const VIRTUAL_WIDTH: f64 = X_VIRTUAL_MAX - X_VIRTUAL_MIN;
-pub const VIRTUAL_HEIGHT: f64 = Y_VIRTUAL_MAX - Y_VIRTUAL_MIN;
+/// Power of the distance, and the number of nearest control points, for `InverseDistanceWeighting`.
+/// 0 neighbours uses all control points.
+pub const IDW_POWER: f64 = 2.0;
+pub const IDW_NEIGHBOURS: usize = 8;
 
-/// Scale the Physical points when interpolating
-pub const X_PHYSICAL_SCALE: f64 = 1.0 * PHYSICAL_WIDTH / (X_PHYSICAL_SUBDIVISIONS as f64);
-pub const Y_PHYSICAL_SCALE: f64 = 1.0 * PHYSICAL_HEIGHT / (Y_PHYSICAL_SUBDIVISIONS as f64);
+/// Regularisation of `ThinPlateSplineInterpolation`, added to the diagonal of the spline system.
+/// 0 passes exactly through the control points, larger values smooth the surface.
+pub const RBF_REGULARISATION: f64 = 0.0;
 
-/// Scale the Virtual points when computing bounding box
-pub const X_VIRTUAL_SCALE: f64 = 1.0 * VIRTUAL_WIDTH / (X_VIRTUAL_SUBDIVISIONS as f64);
-pub const Y_VIRTUAL_SCALE: f64 = 1.0 * VIRTUAL_HEIGHT / (Y_VIRTUAL_SUBDIVISIONS as f64);
+/// Shape parameter ε of the Gaussian and multiquadric kernels, in 1 / Physical pixels
+pub const RBF_SHAPE: f64 = 0.02;
 
-/// Shift the Physical points when interpolating
-pub const PHYSICAL_OFFSET: Vector2<f64> = Vector2 {
-    x: X_PHYSICAL_MIN,  //  Previously GRID_SIZE
-    y: Y_PHYSICAL_MIN,  //  Previously GRID_SIZE
-};
-
-/// Shift the Virtual points when computing bounding box
-pub const VIRTUAL_OFFSET: Vector2<f64> = Vector2 {
-    x: X_VIRTUAL_MIN,
-    y: Y_VIRTUAL_MIN,
-};
+/// Smoothness factor of `SibsonC1Interpolation`
+pub const SIBSON_SMOOTHNESS: f64 = 1.0;
 
 pub const OFFSET: f64 = 0.0;  //  Previously -0.01
 
@@ -0,0 +1,278 @@
+//! Load the control points from a CSV file, so that the projection may be tuned without recompiling.
+//! Each row maps a Physical (x,y) Coordinate on the PineTime Display to a Virtual (x,y) Coordinate on the CHIP-8 Emulator Screen:
+//! ```text
+//! x_physical, y_physical, x_virtual, y_virtual
+//! 1.06194690265487, 0, 0, 0
+//! 44.6017699115044, 0, 10.6666666666667, 0
+//! ```
+//! The header row is optional. If present, the columns may appear in any order.
+//! Blank lines and lines starting with `#` are ignored.
+use std::error::Error;
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::Path;
+
+/// Columns expected in the CSV file, in the default order
+const COLUMNS: [&str; 4] = ["x_physical", "y_physical", "x_virtual", "y_virtual"];
+
+/// Points closer than this fraction of their spread to the line through them count as collinear
+const COLLINEAR_TOLERANCE: f64 = 1e-9;
+
+/// A Physical (x,y) Coordinate and the Virtual (x,y) Coordinate that it should interpolate to
+#[derive(Clone, Copy, Debug, PartialEq, Serialize)]
+pub struct ControlPoint {
+    pub x_physical: f64,
+    pub y_physical: f64,
+    pub x_virtual: f64,
+    pub y_virtual: f64,
+}
+
+/// Virtual X or Y Coordinate of the Control Points, interpolated as the height of the triangulation
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum VirtualAxis {
+    X,
+    Y,
+}
+
+impl VirtualAxis {
+    /// Both axes, in the order that the grids are generated
+    pub const ALL: [VirtualAxis; 2] = [VirtualAxis::X, VirtualAxis::Y];
+
+    /// Return the Virtual Coordinate of `point` on this axis
+    pub fn value(self, point: &ControlPoint) -> f64 {
+        match self {
+            VirtualAxis::X => point.x_virtual,
+            VirtualAxis::Y => point.y_virtual,
+        }
+    }
+
+    /// Index of the channel for this axis, e.g. in `PointWithVirtual::gradients`
+    pub fn index(self) -> usize {
+        match self {
+            VirtualAxis::X => 0,
+            VirtualAxis::Y => 1,
+        }
+    }
+
+    /// Name of the interpolated grid in data.rs
+    pub fn grid_name(self) -> &'static str {
+        match self {
+            VirtualAxis::X => "X_VIRTUAL_GRID",
+            VirtualAxis::Y => "Y_VIRTUAL_GRID",
+        }
+    }
+}
+
+/// Error returned when the control points can't be loaded
+#[derive(Debug)]
+pub enum ControlPointError {
+    /// The file couldn't be read
+    Io(io::Error),
+    /// The header row doesn't name the expected columns
+    Header(String),
+    /// A row couldn't be parsed. `row` is the line number in the file, starting from 1.
+    Malformed { row: usize, reason: String },
+    /// Not enough points to create a triangulation
+    TooFewPoints(usize),
+    /// Two rows have the same Physical (x,y) Coordinate
+    Duplicate { row: usize, first_row: usize },
+    /// All the points lie on one line, so they can't be triangulated. Lists the rows of the points.
+    Collinear(Vec<usize>),
+}
+
+impl fmt::Display for ControlPointError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ControlPointError::Io(err) => write!(f, "failed to read control points: {}", err),
+            ControlPointError::Header(reason) => write!(f, "invalid header: {}", reason),
+            ControlPointError::Malformed { row, reason } => write!(f, "row {}: {}", row, reason),
+            ControlPointError::TooFewPoints(count) => write!(f, "found {} control points, need at least 3", count),
+            ControlPointError::Duplicate { row, first_row } => {
+                write!(f, "row {}: same Physical (x,y) as row {}", row, first_row)
+            }
+            ControlPointError::Collinear(rows) => {
+                let rows: Vec<String> = rows.iter().map(|row| row.to_string()).collect();
+                write!(f, "rows {} lie on one line, so they can't be triangulated", rows.join(", "))
+            }
+        }
+    }
+}
+
+impl Error for ControlPointError {}
+
+impl From<io::Error> for ControlPointError {
+    fn from(err: io::Error) -> ControlPointError {
+        ControlPointError::Io(err)
+    }
+}
+
+/// Load the control points from the CSV file at `path`
+pub fn load_control_points<P: AsRef<Path>>(path: P) -> Result<Vec<ControlPoint>, ControlPointError> {
+    let text = fs::read_to_string(path)?;
+    parse_control_points(&text)
+}
+
+/// Parse the control points from CSV text
+pub fn parse_control_points(text: &str) -> Result<Vec<ControlPoint>, ControlPointError> {
+    //  Index of each column in COLUMNS order. Changed if the header row lists them in another order.
+    let mut order = [0, 1, 2, 3];
+    let mut points = Vec::new();
+    let mut rows = Vec::new();
+    let mut seen_row = false;
+    for (index, line) in text.lines().enumerate() {
+        let row = index + 1;
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') { continue; }
+        let fields: Vec<&str> = line.split(',').map(|field| field.trim()).collect();
+
+        //  The first row is a header if it names the columns. Otherwise it's data, and a field that isn't
+        //  a number is reported below like in any other row.
+        let first_row = !seen_row;
+        seen_row = true;
+        let names_column = |field: &&str| COLUMNS.iter().any(|name| field.eq_ignore_ascii_case(name));
+        if first_row && fields.iter().any(names_column) {
+            order = parse_header(&fields)?;
+            continue;
+        }
+
+        if fields.len() != COLUMNS.len() {
+            return Err(ControlPointError::Malformed {
+                row,
+                reason: format!("expected {} columns, found {}", COLUMNS.len(), fields.len()),
+            });
+        }
+        let mut values = [0.0; 4];
+        for (column, value) in values.iter_mut().enumerate() {
+            let field = fields[order[column]];
+            *value = field.parse::<f64>()
+                .ok()
+                .filter(|value| value.is_finite())
+                .ok_or_else(|| ControlPointError::Malformed {
+                    row,
+                    reason: format!("{} is not a number: `{}`", COLUMNS[column], field),
+                })?;
+        }
+        points.push(ControlPoint {
+            x_physical: values[0],
+            y_physical: values[1],
+            x_virtual: values[2],
+            y_virtual: values[3],
+        });
+        rows.push(row);
+    }
+    if points.len() < 3 {
+        return Err(ControlPointError::TooFewPoints(points.len()));
+    }
+    check_triangulation(&points, &rows)?;
+    Ok(points)
+}
+
+/// Check that the points can be triangulated: no two share a Physical (x,y) and not all lie on one line.
+/// `rows` are the line numbers of the points, for the error.
+fn check_triangulation(points: &[ControlPoint], rows: &[usize]) -> Result<(), ControlPointError> {
+    let position = |point: &ControlPoint| (point.x_physical, point.y_physical);
+    for (index, point) in points.iter().enumerate() {
+        if let Some(first) = points[..index].iter().position(|other| position(other) == position(point)) {
+            return Err(ControlPointError::Duplicate { row: rows[index], first_row: rows[first] });
+        }
+    }
+
+    //  Measure the distance of each point from the line through the first point and the point farthest from it
+    let (x0, y0) = position(&points[0]);
+    let distance2 = |point: &ControlPoint| (point.x_physical - x0).powi(2) + (point.y_physical - y0).powi(2);
+    let farthest = points.iter().max_by(|a, b| distance2(a).partial_cmp(&distance2(b)).unwrap()).unwrap();
+    let (dx, dy) = (farthest.x_physical - x0, farthest.y_physical - y0);
+    let length = (dx * dx + dy * dy).sqrt();
+    let off_line = points.iter().any(|point| {
+        let cross = dx * (point.y_physical - y0) - dy * (point.x_physical - x0);
+        cross.abs() / length > COLLINEAR_TOLERANCE * length
+    });
+    if off_line { Ok(()) } else { Err(ControlPointError::Collinear(rows.to_vec())) }
+}
+
+/// Return the position of each of COLUMNS in the header row
+fn parse_header(fields: &[&str]) -> Result<[usize; 4], ControlPointError> {
+    if fields.len() != COLUMNS.len() {
+        return Err(ControlPointError::Header(format!("expected columns {}", COLUMNS.join(", "))));
+    }
+    let mut order = [0; 4];
+    for (column, name) in COLUMNS.iter().enumerate() {
+        order[column] = fields.iter()
+            .position(|field| field.eq_ignore_ascii_case(name))
+            .ok_or_else(|| ControlPointError::Header(format!("missing column {}", name)))?;
+    }
+    Ok(order)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const HEADER: &str = "x_physical, y_physical, x_virtual, y_virtual\n";
+
+    #[test]
+    fn parses_columns_in_header_order() {
+        let text = "y_virtual, x_virtual, y_physical, x_physical\n0, 0, 0, 0\n0, 10, 0, 40\n5, 0, 30, 0\n";
+        let points = parse_control_points(text).unwrap();
+        assert_eq!(points.len(), 3);
+        assert_eq!(points[1], ControlPoint { x_physical: 40.0, y_physical: 0.0, x_virtual: 10.0, y_virtual: 0.0 });
+    }
+
+    #[test]
+    fn rejects_bad_columns() {
+        match parse_control_points("x_physical, y_physical, x_virtual, z_virtual\n") {
+            Err(ControlPointError::Header(_)) => {}
+            other => panic!("expected a header error, found {:?}", other),
+        }
+        match parse_control_points(&format!("{}0, 0, 0, 0\n1, 2, 3\n", HEADER)) {
+            Err(ControlPointError::Malformed { row: 3, .. }) => {}
+            other => panic!("expected row 3 to be malformed, found {:?}", other),
+        }
+    }
+
+    #[test]
+    fn rejects_bad_first_row_without_header() {
+        match parse_control_points("0, 0, O, 0\n40, 0, 10, 0\n0, 30, 0, 5\n") {
+            Err(ControlPointError::Malformed { row: 1, reason }) => assert!(reason.contains("x_virtual"), "{}", reason),
+            other => panic!("expected row 1 to be malformed, found {:?}", other),
+        }
+    }
+
+    #[test]
+    fn rejects_bad_numbers() {
+        for field in &["abc", "NaN", "inf"] {
+            let text = format!("{}0, 0, 0, 0\n40, {}, 10, 0\n", HEADER, field);
+            match parse_control_points(&text) {
+                Err(ControlPointError::Malformed { row: 3, reason }) => assert!(reason.contains("y_physical"), "{}", reason),
+                other => panic!("expected row 3 to be malformed, found {:?}", other),
+            }
+        }
+    }
+
+    #[test]
+    fn rejects_too_few_points() {
+        match parse_control_points(&format!("{}0, 0, 0, 0\n40, 0, 10, 0\n", HEADER)) {
+            Err(ControlPointError::TooFewPoints(2)) => {}
+            other => panic!("expected too few points, found {:?}", other),
+        }
+    }
+
+    #[test]
+    fn rejects_duplicate_points() {
+        let text = format!("{}0, 0, 0, 0\n40, 0, 10, 0\n0, 30, 0, 5\n40, 0, 11, 0\n", HEADER);
+        match parse_control_points(&text) {
+            Err(ControlPointError::Duplicate { row: 5, first_row: 3 }) => {}
+            other => panic!("expected row 5 to duplicate row 3, found {:?}", other),
+        }
+    }
+
+    #[test]
+    fn rejects_collinear_points() {
+        let text = format!("{}0, 0, 0, 0\n# comment\n40, 20, 10, 5\n80, 40, 20, 10\n", HEADER);
+        match parse_control_points(&text) {
+            Err(ControlPointError::Collinear(rows)) => assert_eq!(rows, vec![2, 4, 5]),
+            other => panic!("expected collinear points, found {:?}", other),
+        }
+    }
+}
@@ -0,0 +1,162 @@
+//! Anti-aliased coverage map: the fraction of each Physical Pixel covered by each Virtual Pixel.
+//! `Grid::from_delaunay_interpolation` samples one point per Physical Pixel, so the edges between Virtual Pixels
+//! look jagged. Here each Physical Pixel is supersampled on a `samples` x `samples` lattice centred on the same point,
+//! and the Virtual Pixels hit by the most samples are kept with their share of the samples as the weight.
+//! With one sample, the coverage map is the same as `PHYSICAL_TO_VIRTUAL_MAP`.
+//! The CHIP-8 Emulator may then blend the colours of up to `max_virtual` Virtual Pixels per Physical Pixel.
+//! Like `PHYSICAL_TO_VIRTUAL_MAP`, the coverage map covers the quadrant only.
+use crate::delaunay_creation::Delaunay;
+use crate::export::to_u8;
+use crate::geometry::DisplayGeometry;
+use crate::interpolation::InterpolationMethod;
+use crate::mapping::virtual_pixel;
+use cgmath as cg;
+use serde_json;
+use std::io::{self, Write};
+
+/// Virtual Pixel and the fraction of a Physical Pixel that it covers
+#[derive(Clone, Copy, Debug, PartialEq, Serialize)]
+pub struct Coverage {
+    pub virtual_x: i32,
+    pub virtual_y: i32,
+    /// Fraction of the Physical Pixel, from 0 to 1. The weights of a Physical Pixel add up to 1.
+    pub weight: f64,
+}
+
+/// Coverage of each Physical Pixel in the quadrant
+#[derive(Serialize)]
+pub struct CoverageMap {
+    pub geometry: DisplayGeometry,
+    /// Samples per Physical Pixel along each axis
+    pub samples: usize,
+    /// Most Virtual Pixels kept per Physical Pixel
+    pub max_virtual: usize,
+    /// Virtual Pixels covering each Physical Pixel, by decreasing weight, indexed by [y][x]
+    pub pixels: Vec<Vec<Vec<Coverage>>>,
+}
+
+impl CoverageMap {
+    /// Supersample each Physical Pixel with the interpolation method
+    pub fn new<I: InterpolationMethod + ?Sized>(
+        method: &I,
+        delaunay: &Delaunay,
+        geometry: &DisplayGeometry,
+        samples: usize,
+        max_virtual: usize
+    ) -> CoverageMap {
+        let pixels = (0..geometry.y_physical_subdivisions()).map(|y| {
+            (0..geometry.x_physical_subdivisions()).map(|x| {
+                //  Count the samples that land in each Virtual Pixel
+                let mut counts: Vec<((i32, i32), usize)> = Vec::new();
+                for sample_y in 0..samples {
+                    for sample_x in 0..samples {
+                        let pos = geometry.transform_physical_point(cg::Point2::new(
+                            x as f64 + (sample_x as f64 + 0.5) / samples as f64 - 0.5,
+                            y as f64 + (sample_y as f64 + 0.5) / samples as f64 - 0.5
+                        ));
+                        let value = method.interpolate(delaunay, pos);
+                        let pixel = virtual_pixel(geometry, value.x, value.y);
+                        match counts.iter_mut().find(|(virtual_pixel, _)| *virtual_pixel == pixel) {
+                            Some((_, count)) => *count += 1,
+                            None => counts.push((pixel, 1)),
+                        }
+                    }
+                }
+
+                //  Keep the Virtual Pixels with the most samples, and share the Physical Pixel among them
+                counts.sort_by(|a, b| b.1.cmp(&a.1).then(a.0.cmp(&b.0)));
+                counts.truncate(max_virtual);
+                let total: usize = counts.iter().map(|(_, count)| count).sum();
+                counts.iter().map(|&((virtual_x, virtual_y), count)| Coverage {
+                    virtual_x,
+                    virtual_y,
+                    weight: count as f64 / total as f64,
+                }).collect()
+            }).collect()
+        }).collect();
+        CoverageMap { geometry: *geometry, samples, max_virtual, pixels }
+    }
+
+    /// Number of Physical Pixels covered by more than one Virtual Pixel, i.e. the anti-aliased edges
+    pub fn blended_pixels(&self) -> usize {
+        self.pixels.iter().flat_map(|row| row.iter()).filter(|pixel| pixel.len() > 1).count()
+    }
+
+    /// Write the weight table as JSON
+    pub fn write_json<W: Write + ?Sized>(&self, out: &mut W) -> io::Result<()> {
+        serde_json::to_writer(&mut *out, self)?;
+        writeln!(out)
+    }
+
+    /// Write the weight table as a Rust static, ready to `include!`:
+    /// `PHYSICAL_COVERAGE_MAP: [[[(u8, u8, u8); N]; W]; H]` with (virtual_x, virtual_y, weight) entries.
+    /// The weights of a Physical Pixel add up to 255. Unused entries are (255, 255, 0).
+    pub fn write_rust<W: Write + ?Sized>(&self, out: &mut W) -> io::Result<()> {
+        let height = self.pixels.len();
+        let width = self.pixels.first().map_or(0, |row| row.len());
+        writeln!(out, "// Generated by nninterpolation {}, do not edit", env!("CARGO_PKG_VERSION"))?;
+        writeln!(out, "// {}x{} samples per Physical Pixel, up to {} Virtual Pixels each", self.samples, self.samples, self.max_virtual)?;
+        writeln!(out, "// {:?}\n", self.geometry)?;
+        writeln!(out, "/// (virtual_x, virtual_y, weight) of the Virtual Pixels covering each Physical Pixel, indexed by [y][x].")?;
+        writeln!(out, "/// The weights of a Physical Pixel add up to 255. Unused entries are (255, 255, 0).")?;
+        writeln!(out, "pub static PHYSICAL_COVERAGE_MAP: [[[(u8, u8, u8); {}]; {}]; {}] = [", self.max_virtual, width, height)?;
+        for row in self.pixels.iter() {
+            write!(out, "    [")?;
+            for pixel in row.iter() {
+                write!(out, "[")?;
+                for (coverage, weight) in pixel.iter().zip(byte_weights(pixel)) {
+                    write!(out, "({},{},{}),", to_u8(coverage.virtual_x)?, to_u8(coverage.virtual_y)?, weight)?;
+                }
+                for _ in pixel.len()..self.max_virtual {
+                    write!(out, "(255,255,0),")?;
+                }
+                write!(out, "],")?;
+            }
+            writeln!(out, "],")?;
+        }
+        writeln!(out, "];")?;
+        Ok(())
+    }
+}
+
+/// Round the weights of a Physical Pixel to bytes that add up to 255
+fn byte_weights(pixel: &[Coverage]) -> Vec<u8> {
+    let mut weights: Vec<u8> = pixel.iter().map(|coverage| (coverage.weight * 255.0).round() as u8).collect();
+    //  Give the rounding error to the largest weight
+    let total: i32 = weights.iter().map(|&weight| weight as i32).sum();
+    if let Some(first) = weights.first_mut() {
+        *first = (*first as i32 + 255 - total) as u8;
+    }
+    weights
+}
+
+#[cfg(test)]
+mod tests {
+    use super::CoverageMap;
+    use crate::delaunay_creation::{builtin_control_points, triangulation_from_control_points};
+    use crate::geometry::DisplayGeometry;
+    use crate::interpolation::Grid;
+    use crate::mapping::{MapLayout, PixelMaps};
+    use crate::parameters::{MethodParameters, METHOD_NAMES};
+    use crate::VirtualAxis;
+
+    #[test]
+    fn one_sample_reproduces_the_map() {
+        let geometry = DisplayGeometry::default();
+        let delaunay = triangulation_from_control_points(&builtin_control_points());
+        let parameters = MethodParameters::default();
+        for name in METHOD_NAMES.iter() {
+            let method = parameters.method(name, &delaunay).unwrap();
+            let grid = Grid::from_delaunay_interpolation(&*method, &delaunay, &geometry);
+            let maps = PixelMaps::new(&geometry, grid.values(VirtualAxis::X), grid.values(VirtualAxis::Y), MapLayout::Quadrant);
+            let coverage = CoverageMap::new(&*method, &delaunay, &geometry, 1, 1);
+            for (map_row, coverage_row) in maps.physical_to_virtual.iter().zip(coverage.pixels.iter()) {
+                for (&pixel, covered) in map_row.iter().zip(coverage_row.iter()) {
+                    assert_eq!(covered.len(), 1, "{}", name);
+                    assert_eq!((covered[0].virtual_x, covered[0].virtual_y), pixel, "{}", name);
+                }
+            }
+            assert_eq!(coverage.pixels.len(), maps.physical_to_virtual.len(), "{}", name);
+        }
+    }
+}
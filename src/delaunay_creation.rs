@@ -12,122 +12,120 @@ use cgmath::{EuclideanSpace, Point2, Point3, Vector3};
 use spade::delaunay::{DelaunayTriangulation, DelaunayWalkLocate, FloatDelaunayTriangulation};
 use spade::HasPosition;
 
+use crate::control_points::{ControlPoint, VirtualAxis};
 //use crate::constants::*;
 //use noise::{NoiseFn, Seedable};
 
-#[cfg(feature = "interpolate_x")]  //  If interpolating X values...
-fn load_data() -> [Point3<f64>; 16] {
-    [
+/// Built-in control points, mapping Physical (x,y) to Virtual (x,y) Coordinates
+pub fn builtin_control_points() -> Vec<ControlPoint> {
+    vec![
         //  Generated by https://docs.google.com/spreadsheets/d/1G9kLS0Es6kwcMA3SC50w5-T-LBYi3NQeY98y7HOAovs/edit#gid=1875321785
-        p(1.06194690265487 as f64, 0 as f64, 0 as f64),
-        p(44.6017699115044 as f64, 0 as f64, 10.6666666666667 as f64),
-        p(84.9557522123894 as f64, 0.888888888888889 as f64, 21.3333333333333 as f64),
-        p(120 as f64, 0 as f64, 32 as f64),
-        p(1.06194690265487 as f64, 35.5555555555556 as f64, 0 as f64),
-        p(42.4778761061947 as f64, 34.6666666666667 as f64, 10.6666666666667 as f64),
-        p(82.8318584070797 as f64, 31.1111111111111 as f64, 21.3333333333333 as f64),
-        p(118.938053097345 as f64, 25.7777777777778 as f64, 32 as f64),
-        p(1.06194690265487 as f64, 70.2222222222222 as f64, 0 as f64),
-        p(38.2300884955752 as f64, 67.5555555555556 as f64, 10.6666666666667 as f64),
-        p(76.4601769911505 as f64, 67.5555555555556 as f64, 21.3333333333333 as f64),
-        p(110.442477876106 as f64, 53.3333333333333 as f64, 32 as f64),
-        p(1.06194690265487 as f64, 99.5555555555556 as f64, 0 as f64),
-        p(31.858407079646 as f64, 97.7777777777778 as f64, 10.6666666666667 as f64),
-        p(64.7787610619469 as f64, 92.4444444444444 as f64, 21.3333333333333 as f64),
-        p(98.7610619469027 as f64, 80 as f64, 32 as f64),
+        //  `SphericalProjection` generates similar control points without the spreadsheet
+        cp(1.06194690265487, 0.0, 0.0, 0.0),
+        cp(44.6017699115044, 0.0, 10.6666666666667, 0.0),
+        cp(84.9557522123894, 0.888888888888889, 21.3333333333333, 0.0),
+        cp(120.0, 0.0, 32.0, 0.0),
+        cp(1.06194690265487, 35.5555555555556, 0.0, 5.33333333333333),
+        cp(42.4778761061947, 34.6666666666667, 10.6666666666667, 5.33333333333333),
+        cp(82.8318584070797, 31.1111111111111, 21.3333333333333, 5.33333333333333),
+        cp(118.938053097345, 25.7777777777778, 32.0, 5.33333333333333),
+        cp(1.06194690265487, 70.2222222222222, 0.0, 10.6666666666667),
+        cp(38.2300884955752, 67.5555555555556, 10.6666666666667, 10.6666666666667),
+        cp(76.4601769911505, 67.5555555555556, 21.3333333333333, 10.6666666666667),
+        cp(110.442477876106, 53.3333333333333, 32.0, 10.6666666666667),
+        cp(1.06194690265487, 99.5555555555556, 0.0, 16.0),
+        cp(31.858407079646, 97.7777777777778, 10.6666666666667, 16.0),
+        cp(64.7787610619469, 92.4444444444444, 21.3333333333333, 16.0),
+        cp(98.7610619469027, 80.0, 32.0, 16.0),
     ]
 }
 
-#[cfg(feature = "interpolate_y")]  //  If interpolating Y values...
-fn load_data() -> [Point3<f64>; 16] {
-    [
-        //  Generated by https://docs.google.com/spreadsheets/d/1G9kLS0Es6kwcMA3SC50w5-T-LBYi3NQeY98y7HOAovs/edit#gid=1875321785
-        p(1.06194690265487 as f64, 0 as f64, 0 as f64),
-        p(44.6017699115044 as f64, 0 as f64, 0 as f64),
-        p(84.9557522123894 as f64, 0.888888888888889 as f64, 0 as f64),
-        p(120 as f64, 0 as f64, 0 as f64),
-        p(1.06194690265487 as f64, 35.5555555555556 as f64, 5.33333333333333 as f64),
-        p(42.4778761061947 as f64, 34.6666666666667 as f64, 5.33333333333333 as f64),
-        p(82.8318584070797 as f64, 31.1111111111111 as f64, 5.33333333333333 as f64),
-        p(118.938053097345 as f64, 25.7777777777778 as f64, 5.33333333333333 as f64),
-        p(1.06194690265487 as f64, 70.2222222222222 as f64, 10.6666666666667 as f64),
-        p(38.2300884955752 as f64, 67.5555555555556 as f64, 10.6666666666667 as f64),
-        p(76.4601769911505 as f64, 67.5555555555556 as f64, 10.6666666666667 as f64),
-        p(110.442477876106 as f64, 53.3333333333333 as f64, 10.6666666666667 as f64),
-        p(1.06194690265487 as f64, 99.5555555555556 as f64, 16 as f64),
-        p(31.858407079646 as f64, 97.7777777777778 as f64, 16 as f64),
-        p(64.7787610619469 as f64, 92.4444444444444 as f64, 16 as f64),
-        p(98.7610619469027 as f64, 80 as f64, 16 as f64),
-    ]
-}
+pub type Delaunay = FloatDelaunayTriangulation<PointWithVirtual, DelaunayWalkLocate>;
 
-pub type Delaunay = FloatDelaunayTriangulation<PointWithHeight, DelaunayWalkLocate>;
-
-pub struct PointWithHeight {
+/// Physical (x,y) point of the triangulation, carrying the Virtual (x,y) point that it maps to.
+/// Each Virtual Coordinate is a channel with its own gradient, indexed by `VirtualAxis::index`.
+pub struct PointWithVirtual {
     point: Point2<f64>,
-    pub height: f64,
-    pub gradient: Point2<f64>,
-    // We don't need the normal for interpolation purposes. We store it only for
+    pub virtual_point: Point2<f64>,
+    pub gradients: [Point2<f64>; 2],
+    // We don't need the normals for interpolation purposes. We store them only for
     // visualization.
-    pub normal: Vector3<f64>,
+    pub normals: [Vector3<f64>; 2],
 }
 
-impl HasPosition for PointWithHeight {
+impl HasPosition for PointWithVirtual {
     type Point = Point2<f64>;
     fn position(&self) -> Point2<f64> {
         self.point
     }
 }
 
-impl PointWithHeight {
-    pub fn position_3d(&self) -> Point3<f64> {
-        Point3::new(self.point.x, self.point.y, self.height)
+impl PointWithVirtual {
+    /// Return the Physical (x,y) point with the Virtual Coordinate on `axis` as the height
+    pub fn position_3d(&self, axis: VirtualAxis) -> Point3<f64> {
+        Point3::new(self.point.x, self.point.y, self.value(axis))
+    }
+
+    /// Return the Virtual Coordinate on `axis`
+    pub fn value(&self, axis: VirtualAxis) -> f64 {
+        match axis {
+            VirtualAxis::X => self.virtual_point.x,
+            VirtualAxis::Y => self.virtual_point.y,
+        }
+    }
+
+    pub fn gradient(&self, axis: VirtualAxis) -> Point2<f64> {
+        self.gradients[axis.index()]
+    }
+
+    pub fn normal(&self, axis: VirtualAxis) -> Vector3<f64> {
+        self.normals[axis.index()]
     }
 
-    pub fn new(point: Point2<f64>, height: f64) -> PointWithHeight {
-        PointWithHeight {
+    pub fn new(point: Point2<f64>, virtual_point: Point2<f64>) -> PointWithVirtual {
+        PointWithVirtual {
             point,
-            height,
-            gradient: Point2::new(0.0, 0.0),
-            normal: Vector3::new(0.0, 0.0, 0.0),
+            virtual_point,
+            gradients: [Point2::new(0.0, 0.0); 2],
+            normals: [Vector3::new(0.0, 0.0, 0.0); 2],
         }
     }
 }
 
 // Triangulation creation and normal estimation
 pub fn generate_random_triangulation() -> Delaunay {
+    triangulation_from_control_points(&builtin_control_points())
+}
+
+/// Create the triangulation from control points, e.g. loaded from a CSV file
+pub fn triangulation_from_control_points(points: &[ControlPoint]) -> Delaunay {
     let mut delaunay = DelaunayTriangulation::with_walk_locate();
 
-    for point in &load_data() {
-        delaunay.insert(PointWithHeight::new(Point2::new(point.x, point.y), point.z));
+    for point in points {
+        delaunay.insert(PointWithVirtual::new(
+            Point2::new(point.x_physical, point.y_physical),
+            Point2::new(point.x_virtual, point.y_virtual),
+        ));
     }
-    
-    /*
-    let mut rng = ::rand::thread_rng();
-    let noise = ::noise::OpenSimplex::new().set_seed(rng.gen());
-    for _ in 0..NUM_POINTS {
-        let x = rng.gen_range(-SAMPLE_REGION, SAMPLE_REGION);
-        let y = rng.gen_range(-SAMPLE_REGION, SAMPLE_REGION);
-        let height = noise.get([x * FREQUENCY, y * FREQUENCY]) * MAX_HEIGHT;
-        // Try out some other height functions, like those:
-        // let height = (x * x + y * y) * 0.3;
-        // let height = (x * 3.).sin() + (y - 2.).exp();
-        delaunay.insert(PointWithHeight::new(Point2::new(x, y), height));
-    }
-    */
 
+    estimate_gradients_and_normals(&mut delaunay);
+    delaunay
+}
+
+fn estimate_gradients_and_normals(delaunay: &mut Delaunay) {
     // Note that, for interpolation, we only need the gradients. For visualization
     // purposes, the normals are also generated and stored within the vertices
-    delaunay.estimate_gradients(&(|v| v.height), &(|v, g| v.gradient = g));
-    delaunay.estimate_normals(
-        &(|v| v.height),
-        &(|v: &mut PointWithHeight, n: Point3<_>| v.normal = n.to_vec()),
-    );
-
-    delaunay
+    for &axis in VirtualAxis::ALL.iter() {
+        let index = axis.index();
+        delaunay.estimate_gradients(&(|v: &PointWithVirtual| v.value(axis)), &(|v: &mut PointWithVirtual, g| v.gradients[index] = g));
+        delaunay.estimate_normals(
+            &(|v: &PointWithVirtual| v.value(axis)),
+            &(|v: &mut PointWithVirtual, n: Point3<_>| v.normals[index] = n.to_vec()),
+        );
+    }
 }
 
-/// Construct a new Point (x,y) with height
-fn p(x: f64, y: f64, z: f64) -> Point3<f64> {
-    Point3::new(x, y, z)
+/// Construct a new Control Point that maps Physical (x,y) to Virtual (x,y)
+fn cp(x_physical: f64, y_physical: f64, x_virtual: f64, y_virtual: f64) -> ControlPoint {
+    ControlPoint { x_physical, y_physical, x_virtual, y_virtual }
 }
\ No newline at end of file
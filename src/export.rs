@@ -0,0 +1,206 @@
+//! Export the maps between Physical and Virtual Pixels as source code for the CHIP-8 Emulator.
+//! Rust output is ready to `include!`, C output is a header for the C firmware.
+//! Both start with a comment that documents how the maps were generated.
+use crate::mapping::PixelMaps;
+use std::io::{self, Write};
+
+/// Bounding Box (left, top, right, bottom) of Physical Pixels, as stored in the exported maps
+pub type ByteBoundingBox = (u8, u8, u8, u8);
+
+/// Bounding Box written for Virtual Pixels that no Physical Pixel maps to
+pub const NO_BOUNDING_BOX: ByteBoundingBox = (255, 255, 255, 255);
+
+/// How the maps were generated, documented in the header of the exported code
+pub struct MapSource<'a> {
+    /// Title of the interpolation method, e.g. "natural neighbor interpolation"
+    pub method: &'a str,
+    /// Where the control points came from, e.g. "built-in" or the CSV path
+    pub control_points: &'a str,
+}
+
+/// Write the maps as Rust statics:
+/// `PHYSICAL_TO_VIRTUAL_MAP: [[(u8, u8); W]; H]` and `VIRTUAL_TO_PHYSICAL_MAP: [[(u8, u8, u8, u8); W]; H]`
+pub fn write_rust_maps<W: Write + ?Sized>(out: &mut W, maps: &PixelMaps, source: &MapSource) -> io::Result<()> {
+    let physical_to_virtual = physical_to_virtual_u8(maps)?;
+    let virtual_to_physical = virtual_to_physical_u8(maps)?;
+    let (physical_width, physical_height) = maps.physical_size();
+    let (virtual_width, virtual_height) = maps.virtual_size();
+
+    for line in header_lines(maps, source) {
+        writeln!(out, "// {}", line)?;
+    }
+    writeln!(out)?;
+
+    writeln!(out, "/// Virtual (x,y) Pixel for each Physical (x,y) Pixel, indexed by [y][x].")?;
+    writeln!(out, "/// Used by the CHIP-8 Emulator to decide which Virtual Pixel to fetch the colour value when rendering a Physical Pixel.")?;
+    writeln!(out, "pub static PHYSICAL_TO_VIRTUAL_MAP: [[(u8, u8); {}]; {}] = [", physical_width, physical_height)?;
+    for row in physical_to_virtual.iter() {
+        write!(out, "    [")?;
+        for (x, y) in row.iter() {
+            write!(out, "({},{}),", x, y)?;
+        }
+        writeln!(out, "],")?;
+    }
+    writeln!(out, "];\n")?;
+
+    writeln!(out, "/// Bounding Box (left, top, right, bottom) of the Physical Pixels for each Virtual (x,y) Pixel, indexed by [y][x].")?;
+    writeln!(out, "/// {:?} if no Physical Pixel maps to the Virtual Pixel.", NO_BOUNDING_BOX)?;
+    writeln!(out, "/// Used by the CHIP-8 Emulator to decide which Physical Pixels to redraw when a Virtual Pixel is updated.")?;
+    writeln!(out, "pub static VIRTUAL_TO_PHYSICAL_MAP: [[(u8, u8, u8, u8); {}]; {}] = [", virtual_width, virtual_height)?;
+    for row in virtual_to_physical.iter() {
+        write!(out, "    [")?;
+        for (left, top, right, bottom) in row.iter() {
+            write!(out, "({},{},{},{}),", left, top, right, bottom)?;
+        }
+        writeln!(out, "],")?;
+    }
+    writeln!(out, "];")?;
+    Ok(())
+}
+
+/// Write the maps as a C header with `static const uint8_t` arrays, include guards and `#define`s for the dimensions:
+/// `PHYSICAL_TO_VIRTUAL_MAP[H][W][2]` holds (x, y) and `VIRTUAL_TO_PHYSICAL_MAP[H][W][4]` holds (left, top, right, bottom)
+pub fn write_c_header<W: Write + ?Sized>(out: &mut W, maps: &PixelMaps, source: &MapSource) -> io::Result<()> {
+    let physical_to_virtual = physical_to_virtual_u8(maps)?;
+    let virtual_to_physical = virtual_to_physical_u8(maps)?;
+    let (physical_width, physical_height) = maps.physical_size();
+    let (virtual_width, virtual_height) = maps.virtual_size();
+    let geometry = &maps.geometry;
+
+    for line in header_lines(maps, source) {
+        writeln!(out, "// {}", line)?;
+    }
+    writeln!(out, "#ifndef NNINTERPOLATION_MAPS_H")?;
+    writeln!(out, "#define NNINTERPOLATION_MAPS_H\n")?;
+    writeln!(out, "#include <stdint.h>\n")?;
+
+    writeln!(out, "// Dimensions of the maps")?;
+    writeln!(out, "#define PHYSICAL_WIDTH  {}", physical_width)?;
+    writeln!(out, "#define PHYSICAL_HEIGHT {}", physical_height)?;
+    writeln!(out, "#define VIRTUAL_WIDTH   {}", virtual_width)?;
+    writeln!(out, "#define VIRTUAL_HEIGHT  {}\n", virtual_height)?;
+
+    writeln!(out, "// Geometry of the quadrant that the maps were generated from")?;
+    let constants = [
+        ("X_PHYSICAL_MIN", geometry.x_physical_min), ("X_PHYSICAL_MAX", geometry.x_physical_max),
+        ("Y_PHYSICAL_MIN", geometry.y_physical_min), ("Y_PHYSICAL_MAX", geometry.y_physical_max),
+        ("X_VIRTUAL_MIN", geometry.x_virtual_min), ("X_VIRTUAL_MAX", geometry.x_virtual_max),
+        ("Y_VIRTUAL_MIN", geometry.y_virtual_min), ("Y_VIRTUAL_MAX", geometry.y_virtual_max),
+        ("X_PHYSICAL_INCREMENT", geometry.x_physical_increment), ("Y_PHYSICAL_INCREMENT", geometry.y_physical_increment),
+        ("X_VIRTUAL_INCREMENT", geometry.x_virtual_increment), ("Y_VIRTUAL_INCREMENT", geometry.y_virtual_increment),
+    ];
+    for &(name, value) in constants.iter() {
+        writeln!(out, "#define {:<20} {}", name, value)?;
+    }
+    writeln!(out)?;
+
+    writeln!(out, "// Bounding Box coordinate for Virtual Pixels that no Physical Pixel maps to")?;
+    writeln!(out, "#define NO_BOUNDING_BOX {}\n", NO_BOUNDING_BOX.0)?;
+
+    writeln!(out, "// Virtual (x, y) Pixel for each Physical Pixel, indexed by [y][x].")?;
+    writeln!(out, "// Used by the CHIP-8 Emulator to decide which Virtual Pixel to fetch the colour value when rendering a Physical Pixel.")?;
+    writeln!(out, "static const uint8_t PHYSICAL_TO_VIRTUAL_MAP[PHYSICAL_HEIGHT][PHYSICAL_WIDTH][2] = {{")?;
+    for row in physical_to_virtual.iter() {
+        write!(out, "    {{")?;
+        for (x, y) in row.iter() {
+            write!(out, "{{{},{}}},", x, y)?;
+        }
+        writeln!(out, "}},")?;
+    }
+    writeln!(out, "}};\n")?;
+
+    writeln!(out, "// Bounding Box (left, top, right, bottom) of the Physical Pixels for each Virtual Pixel, indexed by [y][x].")?;
+    writeln!(out, "// Used by the CHIP-8 Emulator to decide which Physical Pixels to redraw when a Virtual Pixel is updated.")?;
+    writeln!(out, "static const uint8_t VIRTUAL_TO_PHYSICAL_MAP[VIRTUAL_HEIGHT][VIRTUAL_WIDTH][4] = {{")?;
+    for row in virtual_to_physical.iter() {
+        write!(out, "    {{")?;
+        for (left, top, right, bottom) in row.iter() {
+            write!(out, "{{{},{},{},{}}},", left, top, right, bottom)?;
+        }
+        writeln!(out, "}},")?;
+    }
+    writeln!(out, "}};\n")?;
+    writeln!(out, "#endif  // NNINTERPOLATION_MAPS_H")?;
+    Ok(())
+}
+
+/// Lines of the header that documents the generation parameters
+pub(crate) fn header_lines(maps: &PixelMaps, source: &MapSource) -> Vec<String> {
+    let geometry = &maps.geometry;
+    vec![
+        format!("Generated by nninterpolation {}, do not edit", env!("CARGO_PKG_VERSION")),
+        format!("Interpolation method: {}", source.method),
+        format!("Control points: {}", source.control_points),
+        format!("Layout: {:?}", maps.layout),
+        format!(
+            "Physical (x,y): {} to {}, {} to {}, increment {}, {}",
+            geometry.x_physical_min, geometry.x_physical_max, geometry.y_physical_min, geometry.y_physical_max,
+            geometry.x_physical_increment, geometry.y_physical_increment
+        ),
+        format!(
+            "Virtual (x,y): {} to {}, {} to {}, increment {}, {}",
+            geometry.x_virtual_min, geometry.x_virtual_max, geometry.y_virtual_min, geometry.y_virtual_max,
+            geometry.x_virtual_increment, geometry.y_virtual_increment
+        ),
+    ]
+}
+
+/// Convert the map of Physical Pixels to Virtual Pixels to bytes, failing if a pixel doesn't fit
+pub fn physical_to_virtual_u8(maps: &PixelMaps) -> io::Result<Vec<Vec<(u8, u8)>>> {
+    maps.physical_to_virtual.iter().map(|row| {
+        row.iter().map(|&(x, y)| Ok((to_u8(x)?, to_u8(y)?))).collect()
+    }).collect()
+}
+
+/// Convert the map of Virtual Pixels to Physical Bounding Boxes to bytes, failing if a pixel doesn't fit
+pub fn virtual_to_physical_u8(maps: &PixelMaps) -> io::Result<Vec<Vec<ByteBoundingBox>>> {
+    maps.virtual_to_physical.iter().map(|row| {
+        row.iter().map(|bounding_box| match *bounding_box {
+            Some((left, top, right, bottom)) => Ok((to_u8(left)?, to_u8(top)?, to_u8(right)?, to_u8(bottom)?)),
+            None => Ok(NO_BOUNDING_BOX),
+        }).collect()
+    }).collect()
+}
+
+/// Convert a pixel coordinate to a byte. 255 is reserved for `NO_BOUNDING_BOX`.
+pub(crate) fn to_u8(value: i32) -> io::Result<u8> {
+    if (0..255).contains(&value) {
+        Ok(value as u8)
+    } else {
+        Err(io::Error::new(io::ErrorKind::InvalidData, format!("pixel {} doesn't fit in the map, expected 0 to 254", value)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::delaunay_creation::{builtin_control_points, triangulation_from_control_points};
+    use crate::geometry::DisplayGeometry;
+    use crate::interpolation::Grid;
+    use crate::lut::LookupTable;
+    use crate::mapping::MapLayout;
+    use crate::parameters::{MethodParameters, METHOD_NAMES};
+    use crate::VirtualAxis;
+
+    #[test]
+    fn every_method_exports() {
+        let geometry = DisplayGeometry::default();
+        let delaunay = triangulation_from_control_points(&builtin_control_points());
+        let parameters = MethodParameters::default();
+        for &name in METHOD_NAMES.iter() {
+            let method = parameters.method(name, &delaunay).unwrap();
+            let grid = Grid::from_delaunay_interpolation(&*method, &delaunay, &geometry);
+            let (x_virtual_grid, y_virtual_grid) = (grid.values(VirtualAxis::X), grid.values(VirtualAxis::Y));
+            for &layout in [MapLayout::Quadrant, MapLayout::FullScreen].iter() {
+                let maps = PixelMaps::new(&geometry, x_virtual_grid, y_virtual_grid, layout);
+                let source = MapSource { method: method.title(), control_points: "built-in" };
+                let context = format!("{} {:?}", name, layout);
+                write_rust_maps(&mut Vec::new(), &maps, &source).expect(&context);
+                write_c_header(&mut Vec::new(), &maps, &source).expect(&context);
+                LookupTable::from_grids(&geometry, x_virtual_grid, y_virtual_grid, layout, method.title())
+                    .and_then(|lut| lut.to_bytes())
+                    .expect(&context);
+            }
+        }
+    }
+}
@@ -0,0 +1,229 @@
+//! Display geometry of the PineTime Display (Physical) and CHIP-8 Emulator Screen (Virtual), configurable at runtime.
+//! The ranges cover one quadrant of each screen, from its centre to its edges.
+//! The geometry may be loaded from a config file with one `key = value` per line, e.g.
+//! ```text
+//! # PineTime quadrant
+//! x_physical_max = 120
+//! y_physical_max = 100
+//! x_virtual_max  = 32
+//! y_virtual_max  = 16
+//! ```
+//! Keys are the field names of `DisplayGeometry`. Missing keys keep their default values from constants.rs.
+use crate::constants::*;
+use cgmath as cg;
+use std::error::Error;
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::Path;
+
+/// Ranges and increments of the Physical and Virtual (x,y) Coordinates
+#[derive(Clone, Copy, Debug, PartialEq, Serialize)]
+pub struct DisplayGeometry {
+    /// Range of Physical (x,y) coordinates, based on PineTime screen resolution
+    pub x_physical_min: f64,
+    pub x_physical_max: f64,
+    pub y_physical_min: f64,
+    pub y_physical_max: f64,
+
+    /// Range of Virtual (x,y) coordinates, based on CHIP-8 Emulator resolution
+    pub x_virtual_min: f64,
+    pub x_virtual_max: f64,
+    pub y_virtual_min: f64,
+    pub y_virtual_max: f64,
+
+    /// Virtual (x,y) coordinates shall be interpolated for Physical (x,y) coordinates at these increments
+    pub x_physical_increment: f64,
+    pub y_physical_increment: f64,
+
+    /// Bounding Box for Physical (x,y) coordinates shall be computed for Virtual (x,y) coordinates at these increments
+    pub x_virtual_increment: f64,
+    pub y_virtual_increment: f64,
+}
+
+/// Error returned when the geometry can't be loaded or is invalid
+#[derive(Debug)]
+pub enum GeometryError {
+    /// The config file couldn't be read
+    Io(io::Error),
+    /// A line couldn't be parsed. `line` starts from 1.
+    Malformed { line: usize, reason: String },
+    /// The geometry is inconsistent, e.g. max is below min
+    Invalid(String),
+}
+
+impl fmt::Display for GeometryError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            GeometryError::Io(err) => write!(f, "failed to read geometry: {}", err),
+            GeometryError::Malformed { line, reason } => write!(f, "line {}: {}", line, reason),
+            GeometryError::Invalid(reason) => write!(f, "invalid geometry: {}", reason),
+        }
+    }
+}
+
+impl Error for GeometryError {}
+
+impl From<io::Error> for GeometryError {
+    fn from(err: io::Error) -> GeometryError {
+        GeometryError::Io(err)
+    }
+}
+
+impl Default for DisplayGeometry {
+    /// One quadrant of the PineTime Display and CHIP-8 Emulator Screen
+    fn default() -> DisplayGeometry {
+        DisplayGeometry {
+            x_physical_min: X_PHYSICAL_MIN,
+            x_physical_max: X_PHYSICAL_MAX,
+            y_physical_min: Y_PHYSICAL_MIN,
+            y_physical_max: Y_PHYSICAL_MAX,
+            x_virtual_min: X_VIRTUAL_MIN,
+            x_virtual_max: X_VIRTUAL_MAX,
+            y_virtual_min: Y_VIRTUAL_MIN,
+            y_virtual_max: Y_VIRTUAL_MAX,
+            x_physical_increment: X_PHYSICAL_INCREMENT,
+            y_physical_increment: Y_PHYSICAL_INCREMENT,
+            x_virtual_increment: X_VIRTUAL_INCREMENT,
+            y_virtual_increment: Y_VIRTUAL_INCREMENT,
+        }
+    }
+}
+
+impl DisplayGeometry {
+    /// Load the geometry from the config file at `path`, starting from the defaults
+    pub fn load<P: AsRef<Path>>(path: P) -> Result<DisplayGeometry, GeometryError> {
+        let text = fs::read_to_string(path)?;
+        let mut geometry = DisplayGeometry::default();
+        geometry.parse(&text)?;
+        Ok(geometry)
+    }
+
+    /// Update the geometry with the `key = value` lines in `text`
+    pub fn parse(&mut self, text: &str) -> Result<(), GeometryError> {
+        for (index, line) in text.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') { continue; }
+            let malformed = |reason: String| GeometryError::Malformed { line: index + 1, reason };
+            let mut parts = line.splitn(2, '=');
+            let key = parts.next().unwrap_or("").trim();
+            let value = parts.next()
+                .ok_or_else(|| malformed(format!("expected `key = value`, found `{}`", line)))?
+                .trim();
+            let value = value.parse::<f64>()
+                .map_err(|_| malformed(format!("{} is not a number: `{}`", key, value)))?;
+            self.set(key, value).map_err(malformed)?;
+        }
+        self.validate()
+    }
+
+    /// Set the field named `key`
+    pub fn set(&mut self, key: &str, value: f64) -> Result<(), String> {
+        let field = match key {
+            "x_physical_min" => &mut self.x_physical_min,
+            "x_physical_max" => &mut self.x_physical_max,
+            "y_physical_min" => &mut self.y_physical_min,
+            "y_physical_max" => &mut self.y_physical_max,
+            "x_virtual_min" => &mut self.x_virtual_min,
+            "x_virtual_max" => &mut self.x_virtual_max,
+            "y_virtual_min" => &mut self.y_virtual_min,
+            "y_virtual_max" => &mut self.y_virtual_max,
+            "x_physical_increment" => &mut self.x_physical_increment,
+            "y_physical_increment" => &mut self.y_physical_increment,
+            "x_virtual_increment" => &mut self.x_virtual_increment,
+            "y_virtual_increment" => &mut self.y_virtual_increment,
+            _ => return Err(format!("unknown key `{}`", key)),
+        };
+        *field = value;
+        Ok(())
+    }
+
+    /// Check that the ranges are not empty and the increments are positive
+    pub fn validate(&self) -> Result<(), GeometryError> {
+        let ranges = [
+            ("x_physical", self.x_physical_min, self.x_physical_max, self.x_physical_increment),
+            ("y_physical", self.y_physical_min, self.y_physical_max, self.y_physical_increment),
+            ("x_virtual", self.x_virtual_min, self.x_virtual_max, self.x_virtual_increment),
+            ("y_virtual", self.y_virtual_min, self.y_virtual_max, self.y_virtual_increment),
+        ];
+        for &(name, min, max, increment) in ranges.iter() {
+            if !(min.is_finite() && max.is_finite() && max > min) {
+                return Err(GeometryError::Invalid(format!("{}_max must be greater than {}_min", name, name)));
+            }
+            if !(increment.is_finite() && increment > 0.0 && increment <= max - min) {
+                return Err(GeometryError::Invalid(format!("{}_increment must be between 0 and the range", name)));
+            }
+        }
+        Ok(())
+    }
+
+    /// How many divisions in the Physical X and Y axes to be interpolated
+    pub fn x_physical_subdivisions(&self) -> usize {
+        ((self.x_physical_max - self.x_physical_min) / self.x_physical_increment) as usize
+    }
+    pub fn y_physical_subdivisions(&self) -> usize {
+        ((self.y_physical_max - self.y_physical_min) / self.y_physical_increment) as usize
+    }
+
+    /// How many divisions in the Virtual X and Y axes to be computed for Bounding Box
+    pub fn x_virtual_subdivisions(&self) -> usize {
+        ((self.x_virtual_max - self.x_virtual_min) / self.x_virtual_increment) as usize
+    }
+    pub fn y_virtual_subdivisions(&self) -> usize {
+        ((self.y_virtual_max - self.y_virtual_min) / self.y_virtual_increment) as usize
+    }
+
+    /// Width and height of the PineTime Display
+    pub fn physical_width(&self) -> f64 {
+        (self.x_physical_max - self.x_physical_min) * PHYSICAL_MARGIN
+    }
+    pub fn physical_height(&self) -> f64 {
+        (self.y_physical_max - self.y_physical_min) * PHYSICAL_MARGIN
+    }
+
+    /// Width and height of the CHIP-8 Emulator Display
+    pub fn virtual_width(&self) -> f64 {
+        self.x_virtual_max - self.x_virtual_min
+    }
+    pub fn virtual_height(&self) -> f64 {
+        self.y_virtual_max - self.y_virtual_min
+    }
+
+    /// Scale the Physical points when interpolating
+    pub fn x_physical_scale(&self) -> f64 {
+        self.physical_width() / (self.x_physical_subdivisions() as f64)
+    }
+    pub fn y_physical_scale(&self) -> f64 {
+        self.physical_height() / (self.y_physical_subdivisions() as f64)
+    }
+
+    /// Scale the Virtual points when computing bounding box
+    pub fn x_virtual_scale(&self) -> f64 {
+        self.virtual_width() / (self.x_virtual_subdivisions() as f64)
+    }
+    pub fn y_virtual_scale(&self) -> f64 {
+        self.virtual_height() / (self.y_virtual_subdivisions() as f64)
+    }
+
+    /// Given a normalised point, return the Physical (x,y) Coordinates
+    pub fn transform_physical_point(&self, v: cg::Point2<f64>) -> cg::Point2<f64> {
+        cg::Point2::new(
+            self.x_physical_min + v.x * self.x_physical_scale(),
+            self.y_physical_min + v.y * self.y_physical_scale()
+        )
+    }
+
+    /// Given a normalised point, return the Virtual (x,y) Coordinates
+    pub fn transform_virtual_point(&self, v: cg::Point2<f64>) -> cg::Point2<f64> {
+        cg::Point2::new(
+            self.x_virtual_min + v.x * self.x_virtual_scale(),
+            self.y_virtual_min + v.y * self.y_virtual_scale()
+        )
+    }
+
+    /// Return true if `grid` has one value per interpolated Physical (x,y) Coordinate
+    pub fn fits_grid<R: AsRef<[f64]>>(&self, grid: &[R]) -> bool {
+        grid.len() == self.y_physical_subdivisions() + 1 &&
+            grid.iter().all(|row| row.as_ref().len() == self.x_physical_subdivisions() + 1)
+    }
+}
@@ -0,0 +1,113 @@
+//! Export the map of Physical Pixels to Virtual Pixels as a GPU texture, with a shader snippet that samples
+//! the CHIP-8 framebuffer through it, so that the desktop preview shows exactly what the PineTime shows.
+//! The texture is a 16-bit RGB PNG, one texel per Physical Pixel: red is the Virtual x and green is the Virtual y,
+//! normalised to the centre of the Virtual Pixel, i.e. `(virtual + 0.5) / virtual_size`. Blue is always 0.
+//! Image loaders return the channels in that order, so the snippets read `.rg`.
+//! Sample it with `texelFetch` / `textureLoad`, without filtering.
+use crate::mapping::PixelMaps;
+use png;
+use std::io::{self, Write};
+
+/// Return the texture data by rows, two u16 channels per Physical Pixel.
+/// Virtual Pixels outside the framebuffer, e.g. at the edge of the quadrant, are clamped to the nearest Virtual Pixel.
+pub fn texture_lut(maps: &PixelMaps) -> Vec<u16> {
+    let (virtual_width, virtual_height) = maps.virtual_size();
+    let normalise = |virtual_pixel: i32, size: usize| {
+        let virtual_pixel = virtual_pixel.clamp(0, size as i32 - 1);
+        ((virtual_pixel as f64 + 0.5) / size as f64 * u16::MAX as f64).round() as u16
+    };
+    maps.physical_to_virtual.iter()
+        .flat_map(|row| row.iter())
+        .flat_map(|&(x, y)| vec![normalise(x, virtual_width), normalise(y, virtual_height)])
+        .collect()
+}
+
+/// Write the texture as a 16-bit RGB PNG, with the Virtual x in red, the Virtual y in green and 0 in blue
+pub fn write_texture_png<W: Write>(out: W, maps: &PixelMaps) -> io::Result<()> {
+    let (width, height) = maps.physical_size();
+    let mut encoder = png::Encoder::new(out, width as u32, height as u32);
+    encoder.set_color(png::ColorType::Rgb);
+    encoder.set_depth(png::BitDepth::Sixteen);
+    let data: Vec<u8> = texture_lut(maps).chunks(2)
+        .flat_map(|texel| vec![texel[0], texel[1], 0])
+        .flat_map(|value| value.to_be_bytes().to_vec())
+        .collect();
+    encoder.write_header()
+        .and_then(|mut writer| writer.write_image_data(&data))
+        .map_err(io::Error::other)
+}
+
+/// Write a GLSL (3.30 / ES 3.00) function that returns the colour of a Physical Pixel
+pub fn write_glsl<W: Write + ?Sized>(out: &mut W, maps: &PixelMaps) -> io::Result<()> {
+    let (physical_width, physical_height) = maps.physical_size();
+    let (virtual_width, virtual_height) = maps.virtual_size();
+    writeln!(out, "// Generated by nninterpolation {}, do not edit", env!("CARGO_PKG_VERSION"))?;
+    writeln!(out, "// Sample the CHIP-8 framebuffer through the Physical to Virtual map ({:?})", maps.layout)?;
+    writeln!(out, "// physical_to_virtual: {}x{} texture from the generated RGB16 PNG, Virtual (x,y) in .rg", physical_width, physical_height)?;
+    writeln!(out, "// chip8_framebuffer: {}x{} texture", virtual_width, virtual_height)?;
+    writeln!(out, "uniform sampler2D physical_to_virtual;")?;
+    writeln!(out, "uniform sampler2D chip8_framebuffer;")?;
+    writeln!(out, "const ivec2 PHYSICAL_SIZE = ivec2({}, {});", physical_width, physical_height)?;
+    writeln!(out, "const ivec2 VIRTUAL_SIZE = ivec2({}, {});\n", virtual_width, virtual_height)?;
+    writeln!(out, "vec4 sample_chip8(ivec2 physical_pixel) {{")?;
+    writeln!(out, "    vec2 virtual_uv = texelFetch(physical_to_virtual, physical_pixel, 0).rg;")?;
+    writeln!(out, "    ivec2 virtual_pixel = ivec2(virtual_uv * vec2(VIRTUAL_SIZE));")?;
+    writeln!(out, "    return texelFetch(chip8_framebuffer, virtual_pixel, 0);")?;
+    writeln!(out, "}}")?;
+    Ok(())
+}
+
+/// Write a WGSL function that returns the colour of a Physical Pixel
+pub fn write_wgsl<W: Write + ?Sized>(out: &mut W, maps: &PixelMaps) -> io::Result<()> {
+    let (physical_width, physical_height) = maps.physical_size();
+    let (virtual_width, virtual_height) = maps.virtual_size();
+    writeln!(out, "// Generated by nninterpolation {}, do not edit", env!("CARGO_PKG_VERSION"))?;
+    writeln!(out, "// Sample the CHIP-8 framebuffer through the Physical to Virtual map ({:?})", maps.layout)?;
+    writeln!(out, "// physical_to_virtual: {}x{} Rgba16Unorm texture from the generated RGB16 PNG, Virtual (x,y) in .rg", physical_width, physical_height)?;
+    writeln!(out, "// chip8_framebuffer: {}x{} texture", virtual_width, virtual_height)?;
+    writeln!(out, "@group(0) @binding(0) var physical_to_virtual: texture_2d<f32>;")?;
+    writeln!(out, "@group(0) @binding(1) var chip8_framebuffer: texture_2d<f32>;")?;
+    writeln!(out, "const PHYSICAL_SIZE = vec2<i32>({}, {});", physical_width, physical_height)?;
+    writeln!(out, "const VIRTUAL_SIZE = vec2<i32>({}, {});\n", virtual_width, virtual_height)?;
+    writeln!(out, "fn sample_chip8(physical_pixel: vec2<i32>) -> vec4<f32> {{")?;
+    writeln!(out, "    let virtual_uv = textureLoad(physical_to_virtual, physical_pixel, 0).rg;")?;
+    writeln!(out, "    let virtual_pixel = vec2<i32>(virtual_uv * vec2<f32>(VIRTUAL_SIZE));")?;
+    writeln!(out, "    return textureLoad(chip8_framebuffer, virtual_pixel, 0);")?;
+    writeln!(out, "}}")?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::delaunay_creation::{builtin_control_points, triangulation_from_control_points};
+    use crate::geometry::DisplayGeometry;
+    use crate::interpolation::interpolation_methods::NaturalNeighborInterpolation;
+    use crate::interpolation::Grid;
+    use crate::mapping::MapLayout;
+    use crate::VirtualAxis;
+
+    #[test]
+    fn texture_png_has_virtual_pixels_in_red_and_green() {
+        let geometry = DisplayGeometry::default();
+        let delaunay = triangulation_from_control_points(&builtin_control_points());
+        let grid = Grid::from_delaunay_interpolation(&NaturalNeighborInterpolation, &delaunay, &geometry);
+        let maps = PixelMaps::new(&geometry, grid.values(VirtualAxis::X), grid.values(VirtualAxis::Y), MapLayout::Quadrant);
+        let mut png_bytes = Vec::new();
+        write_texture_png(&mut png_bytes, &maps).unwrap();
+
+        let mut reader = png::Decoder::new(io::Cursor::new(png_bytes)).read_info().unwrap();
+        let mut data = vec![0; reader.output_buffer_size()];
+        let info = reader.next_frame(&mut data).unwrap();
+        assert_eq!((info.color_type, info.bit_depth), (png::ColorType::Rgb, png::BitDepth::Sixteen));
+
+        //  Read back the Virtual Pixel from the red and green channels, as the shader snippets do
+        let (virtual_width, virtual_height) = maps.virtual_size();
+        let texels = data[..info.buffer_size()].chunks(6)
+            .map(|texel| [0, 2, 4].iter().map(|&i| u16::from_be_bytes([texel[i], texel[i + 1]])).collect::<Vec<_>>());
+        for (texel, &(x, y)) in texels.zip(maps.physical_to_virtual.iter().flat_map(|row| row.iter())) {
+            let channel = |value: u16, size: usize| (value as f64 / u16::MAX as f64 * size as f64) as i32;
+            assert_eq!((channel(texel[0], virtual_width), channel(texel[1], virtual_height), texel[2]), (x, y, 0));
+        }
+    }
+}
@@ -8,32 +8,73 @@
 
 use crate::cg_vec_to_na;
 use crate::constants::*;
-use crate::delaunay_creation::{Delaunay, PointWithHeight};
+use crate::control_points::VirtualAxis;
+use crate::delaunay_creation::Delaunay;
+use crate::geometry::DisplayGeometry;
 use cgmath as cg;
-use cgmath::EuclideanSpace;
 use nalgebra as na;
+use std::io::{self, Write};
 
 // Interpolation Methods ------------------------------
+/// Interpolation method, carrying its parameters. Usable as `Box<dyn InterpolationMethod>`,
+/// so that the methods may be chosen at runtime, see `MethodParameters::method`.
 pub trait InterpolationMethod {
-    fn interpolate(d: &Delaunay, point: cg::Point2<f64>) -> f64;
-    fn title() -> &'static str;
+    /// Returns the Virtual (x,y) point interpolated at the Physical (x,y) point
+    fn interpolate(&self, d: &Delaunay, point: cg::Point2<f64>) -> cg::Point2<f64>;
+    fn title(&self) -> &'static str;
 }
 
 pub mod interpolation_methods {
     use super::InterpolationMethod;
-    use crate::delaunay_creation::Delaunay;
+    use crate::constants::{IDW_NEIGHBOURS, IDW_POWER, SIBSON_SMOOTHNESS};
+    use crate::control_points::VirtualAxis;
+    use crate::delaunay_creation::{Delaunay, PointWithVirtual};
+    use crate::lattice::ControlLattice;
     use cgmath as cg;
+    use cgmath::{EuclideanSpace, InnerSpace, MetricSpace};
+    use nalgebra as na;
+    use spade::delaunay::PositionInTriangulation;
+    use spade::primitives::{SimpleEdge, SimpleTriangle};
+    use spade::HasPosition;
+    use std::error::Error;
+    use std::fmt;
+
+    /// Interpolate each Virtual Coordinate separately. Used by the natural neighbor methods,
+    /// since spade doesn't expose the natural neighbor weights to share between the channels.
+    fn per_axis<F: Fn(VirtualAxis) -> f64>(f: F) -> cg::Point2<f64> {
+        cg::Point2::new(f(VirtualAxis::X), f(VirtualAxis::Y))
+    }
 
     pub struct BarycentricInterpolation;
 
     impl InterpolationMethod for BarycentricInterpolation {
-        fn interpolate(delaunay: &Delaunay, point: cg::Point2<f64>) -> f64 {
-            delaunay
-                .barycentric_interpolation(&point, |v| v.height)
-                .unwrap()
+        // Locates the point once and applies the same weights to both Virtual Coordinates
+        fn interpolate(&self, delaunay: &Delaunay, point: cg::Point2<f64>) -> cg::Point2<f64> {
+            let interpolate_edge = |from: &PointWithVirtual, to: &PointWithVirtual| {
+                let edge = SimpleEdge::new(from.position(), to.position());
+                let w1 = edge.project_point(&point).clamp(0.0, 1.0);
+                cg::Point2::from_vec(from.virtual_point.to_vec() * (1.0 - w1) + to.virtual_point.to_vec() * w1)
+            };
+            match delaunay.locate(&point) {
+                PositionInTriangulation::OnPoint(v) => v.virtual_point,
+                PositionInTriangulation::OnEdge(e) | PositionInTriangulation::OutsideConvexHull(e) => {
+                    interpolate_edge(&e.from(), &e.to())
+                }
+                PositionInTriangulation::InTriangle(f) => {
+                    let vs = f.as_triangle();
+                    let triangle = SimpleTriangle::new(vs[0].position(), vs[1].position(), vs[2].position());
+                    let weights = triangle.barycentric_interpolation(&point);
+                    cg::Point2::from_vec(
+                        vs[0].virtual_point.to_vec() * weights.x
+                            + vs[1].virtual_point.to_vec() * weights.y
+                            + vs[2].virtual_point.to_vec() * weights.z,
+                    )
+                }
+                PositionInTriangulation::NoTriangulationPresent => panic!("no triangulation present"),
+            }
         }
 
-        fn title() -> &'static str {
+        fn title(&self) -> &'static str {
             "barycentric interpolation"
         }
     }
@@ -41,81 +82,437 @@ pub mod interpolation_methods {
     pub struct NaturalNeighborInterpolation;
 
     impl InterpolationMethod for NaturalNeighborInterpolation {
-        fn interpolate(delaunay: &Delaunay, point: cg::Point2<f64>) -> f64 {
-            delaunay.nn_interpolation(&point, |v| v.height).unwrap()
+        fn interpolate(&self, delaunay: &Delaunay, point: cg::Point2<f64>) -> cg::Point2<f64> {
+            per_axis(|axis| delaunay.nn_interpolation(&point, |v| v.value(axis)).unwrap())
         }
 
-        fn title() -> &'static str {
+        fn title(&self) -> &'static str {
             "natural neighbor interpolation"
         }
     }
 
-    pub struct SibsonC1Interpolation;
+    pub struct SibsonC1Interpolation {
+        /// Smoothness factor, e.g. 0.5 or 2.0 [default: `SIBSON_SMOOTHNESS`]
+        pub smoothness: f64,
+    }
+
+    impl Default for SibsonC1Interpolation {
+        fn default() -> SibsonC1Interpolation {
+            SibsonC1Interpolation { smoothness: SIBSON_SMOOTHNESS }
+        }
+    }
+
     impl InterpolationMethod for SibsonC1Interpolation {
-        fn interpolate(delaunay: &Delaunay, point: cg::Point2<f64>) -> f64 {
-            delaunay
-                .nn_interpolation_c1_sibson(
-                    &point,
-                    // Check out different smoothness factors
-                    // 0.5,
-                    // 2.0,
-                    1.0,
-                    // The second function defines the gradient of a point
-                    |v| v.height,
-                    |_, v| v.gradient,
-                )
-                .unwrap()
-        }
-
-        fn title() -> &'static str {
+        fn interpolate(&self, delaunay: &Delaunay, point: cg::Point2<f64>) -> cg::Point2<f64> {
+            per_axis(|axis| {
+                delaunay
+                    .nn_interpolation_c1_sibson(
+                        &point,
+                        self.smoothness,
+                        // The second function defines the gradient of a point
+                        |v| v.value(axis),
+                        |_, v| v.gradient(axis),
+                    )
+                    .unwrap()
+            })
+        }
+
+        fn title(&self) -> &'static str {
             "sibson's c1 interpolation"
         }
     }
 
     pub struct FarinC1Interpolation;
     impl InterpolationMethod for FarinC1Interpolation {
-        fn interpolate(delaunay: &Delaunay, point: cg::Point2<f64>) -> f64 {
-            delaunay
-                .nn_interpolation_c1_farin(
-                    &point,
-                    // The second function defines the gradient of a point
-                    |v| v.height,
-                    |_, v| v.gradient,
-                )
-                .unwrap()
-        }
-
-        fn title() -> &'static str {
+        fn interpolate(&self, delaunay: &Delaunay, point: cg::Point2<f64>) -> cg::Point2<f64> {
+            per_axis(|axis| {
+                delaunay
+                    .nn_interpolation_c1_farin(
+                        &point,
+                        // The second function defines the gradient of a point
+                        |v| v.value(axis),
+                        |_, v| v.gradient(axis),
+                    )
+                    .unwrap()
+            })
+        }
+
+        fn title(&self) -> &'static str {
             "farin's c1 interpolation"
         }
     }
+
+    /// Bicubic Catmull-Rom surface over the quads of the control point lattice, see `ControlLattice`.
+    /// The lattice is found when the method is created. If the control points don't form a lattice, it falls back
+    /// to natural neighbor interpolation and says so in its title, so that callers may warn about it.
+    pub struct BicubicLatticeInterpolation {
+        control_lattice: Option<ControlLattice>,
+    }
+
+    impl BicubicLatticeInterpolation {
+        /// Title when the control points don't form a lattice
+        pub const FALLBACK_TITLE: &'static str = "natural neighbor interpolation (bicubic without a lattice)";
+
+        /// Find the lattice of the control points, detected from their Virtual (x,y) or of the given size
+        /// (columns, rows). Fails if the control points don't fill a lattice of the given size.
+        pub fn new(delaunay: &Delaunay, lattice: Option<(usize, usize)>) -> Result<BicubicLatticeInterpolation, String> {
+            let control_lattice = match lattice {
+                Some((columns, rows)) => Some(ControlLattice::new(delaunay, columns, rows).ok_or_else(|| format!(
+                    "the {} control points don't form a {}x{} lattice", delaunay.num_vertices(), columns, rows
+                ))?),
+                None => ControlLattice::detect(delaunay),
+            };
+            Ok(BicubicLatticeInterpolation { control_lattice })
+        }
+
+        /// Lattice of the control points, or `None` if it fell back to natural neighbor interpolation
+        pub fn control_lattice(&self) -> Option<&ControlLattice> {
+            self.control_lattice.as_ref()
+        }
+    }
+
+    impl InterpolationMethod for BicubicLatticeInterpolation {
+        fn interpolate(&self, delaunay: &Delaunay, point: cg::Point2<f64>) -> cg::Point2<f64> {
+            //  Points that the lattice can't locate also fall back to natural neighbor interpolation
+            self.control_lattice.as_ref()
+                .and_then(|lattice| lattice.interpolate(point))
+                .unwrap_or_else(|| NaturalNeighborInterpolation.interpolate(delaunay, point))
+        }
+
+        fn title(&self) -> &'static str {
+            match self.control_lattice {
+                Some(_) => "bicubic lattice interpolation",
+                None => BicubicLatticeInterpolation::FALLBACK_TITLE,
+            }
+        }
+    }
+
+    /// Clough-Tocher piecewise cubic: each triangle is split at its centroid into three cubic Bézier patches,
+    /// built from the values and the gradients that `estimate_gradients` stored in the control points.
+    /// C1 across the triangles, since the normal derivative is linear along each edge.
+    pub struct CloughTocherInterpolation;
+    impl InterpolationMethod for CloughTocherInterpolation {
+        fn interpolate(&self, delaunay: &Delaunay, point: cg::Point2<f64>) -> cg::Point2<f64> {
+            match delaunay.locate(&point) {
+                PositionInTriangulation::OnPoint(v) => v.virtual_point,
+                PositionInTriangulation::OnEdge(e) | PositionInTriangulation::OutsideConvexHull(e) => {
+                    //  Outside the convex hull, project the point onto the nearest edge like the barycentric method
+                    let (from, to) = (e.from(), e.to());
+                    let s = SimpleEdge::new(from.position(), to.position()).project_point(&point).clamp(0.0, 1.0);
+                    per_axis(|axis| clough_tocher_edge(&from, &to, axis, s))
+                }
+                PositionInTriangulation::InTriangle(f) => {
+                    let vs = f.as_triangle();
+                    let triangle = SimpleTriangle::new(vs[0].position(), vs[1].position(), vs[2].position());
+                    let weights = triangle.barycentric_interpolation(&point);
+                    per_axis(|axis| clough_tocher_triangle([&*vs[0], &*vs[1], &*vs[2]], axis, [weights.x, weights.y, weights.z]))
+                }
+                PositionInTriangulation::NoTriangulationPresent => panic!("no triangulation present"),
+            }
+        }
+
+        fn title(&self) -> &'static str {
+            "clough-tocher interpolation"
+        }
+    }
+
+    /// Cubic Hermite curve along the edge from `from` to `to`, at `s` from 0 to 1
+    fn clough_tocher_edge(from: &PointWithVirtual, to: &PointWithVirtual, axis: VirtualAxis, s: f64) -> f64 {
+        let edge = to.position() - from.position();
+        let b0 = from.value(axis);
+        let b1 = b0 + from.gradient(axis).to_vec().dot(edge) / 3.0;
+        let b3 = to.value(axis);
+        let b2 = b3 - to.gradient(axis).to_vec().dot(edge) / 3.0;
+        let t = 1.0 - s;
+        b0 * t * t * t + 3.0 * b1 * t * t * s + 3.0 * b2 * t * s * s + b3 * s * s * s
+    }
+
+    /// Evaluate the Clough-Tocher patch of the triangle at the barycentric coordinates `weights`
+    fn clough_tocher_triangle(vs: [&PointWithVirtual; 3], axis: VirtualAxis, weights: [f64; 3]) -> f64 {
+        let position = |i: usize| vs[i % 3].position();
+        let centroid = cg::Point2::from_vec((position(0).to_vec() + position(1).to_vec() + position(2).to_vec()) / 3.0);
+
+        //  Vertex values, and the edge control points next to each vertex: edge[i][j] on the edge from i to j
+        let value = |i: usize| vs[i % 3].value(axis);
+        let edge = |i: usize, j: usize| value(i) + vs[i % 3].gradient(axis).to_vec().dot(position(j) - position(i)) / 3.0;
+        //  Control points between each vertex and the centroid, in the tangent plane of the vertex
+        let inner = |i: usize| (value(i) + edge(i, i + 1) + edge(i, i + 2)) / 3.0;
+
+        //  Control point between the edge from i to i + 1 and the centroid, chosen so that the derivative
+        //  normal to the edge is linear along the edge
+        let cross = |i: usize| {
+            let (p, q) = (position(i), position(i + 1));
+            let tangent = q - p;
+            let to_centroid = centroid - cg::Point2::from_vec((p.to_vec() + q.to_vec()) / 2.0);
+            let beta = to_centroid.dot(tangent) / tangent.dot(tangent);
+            let (b300, b210, b120, b030) = (value(i), edge(i, i + 1), edge(i + 1, i), value(i + 1));
+            let k0 = inner(i) - (b300 + b210) / 2.0;
+            let k2 = inner(i + 1) - (b120 + b030) / 2.0;
+            let d0 = b210 - b300;
+            let d1 = b120 - b210;
+            let d2 = b030 - b120;
+            (b210 + b120) / 2.0 + (k0 + k2) / 2.0 + beta * (d1 - (d0 + d2) / 2.0)
+        };
+        //  Control points next to the centroid, so that the three patches join with C1 continuity
+        let near_centroid = |i: usize| (inner(i) + cross(i) + cross(i + 2)) / 3.0;
+        let centre = (near_centroid(0) + near_centroid(1) + near_centroid(2)) / 3.0;
+
+        //  The point is in the patch opposite the vertex with the smallest barycentric coordinate
+        let k = (0..3).min_by(|&a, &b| weights[a].partial_cmp(&weights[b]).unwrap()).unwrap();
+        let (i, j) = ((k + 1) % 3, (k + 2) % 3);
+        let (u, v, w) = (weights[i] - weights[k], weights[j] - weights[k], 3.0 * weights[k]);
+        value(i) * u * u * u + value(j) * v * v * v + centre * w * w * w
+            + 3.0 * (edge(i, j) * u * u * v + edge(j, i) * u * v * v)
+            + 3.0 * (inner(i) * u * u * w + inner(j) * v * v * w)
+            + 3.0 * (near_centroid(i) * u * w * w + near_centroid(j) * v * w * w)
+            + 6.0 * cross(i) * u * v * w
+    }
+
+    /// Shepard's inverse distance weighting over the nearest `neighbours` control points, with weights
+    /// 1 / distance ^ `power`. Doesn't use the triangulation, so it's a baseline for the other methods.
+    pub struct InverseDistanceWeighting {
+        /// Power of the distance [default: `IDW_POWER`]
+        pub power: f64,
+        /// Number of nearest control points, 0 for all [default: `IDW_NEIGHBOURS`]
+        pub neighbours: usize,
+    }
+
+    impl Default for InverseDistanceWeighting {
+        fn default() -> InverseDistanceWeighting {
+            InverseDistanceWeighting { power: IDW_POWER, neighbours: IDW_NEIGHBOURS }
+        }
+    }
+
+    impl InterpolationMethod for InverseDistanceWeighting {
+        fn interpolate(&self, delaunay: &Delaunay, point: cg::Point2<f64>) -> cg::Point2<f64> {
+            let mut neighbours: Vec<(f64, cg::Point2<f64>)> = delaunay.vertices()
+                .map(|v| (v.position().distance(point), v.virtual_point))
+                .collect();
+            if self.neighbours > 0 && self.neighbours < neighbours.len() {
+                neighbours.select_nth_unstable_by(self.neighbours - 1, |a, b| a.0.partial_cmp(&b.0).unwrap());
+                neighbours.truncate(self.neighbours);
+            }
+            let (nearest_distance, nearest_point) = neighbours.iter()
+                .cloned()
+                .min_by(|a, b| a.0.partial_cmp(&b.0).unwrap())
+                .expect("no triangulation present");
+            //  On a control point, return its Virtual Coordinates instead of dividing by zero
+            if nearest_distance == 0.0 { return nearest_point; }
+
+            //  Weights relative to the nearest control point, from 1 down, so that large powers don't underflow to 0
+            let mut sum = cg::Vector2::new(0.0, 0.0);
+            let mut total_weight = 0.0;
+            for &(distance, virtual_point) in neighbours.iter() {
+                let weight = (nearest_distance / distance).powf(self.power);
+                sum += virtual_point.to_vec() * weight;
+                total_weight += weight;
+            }
+            cg::Point2::from_vec(sum / total_weight)
+        }
+
+        fn title(&self) -> &'static str {
+            "inverse distance weighting"
+        }
+    }
+
+    /// Radial basis function of the distance r to a control point, with the shape parameter ε
+    #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+    pub enum RadialBasisKernel {
+        /// r² ln r, the thin-plate spline. Ignores ε.
+        ThinPlate,
+        /// exp(-(εr)²)
+        Gaussian,
+        /// √(1 + (εr)²)
+        Multiquadric,
+        /// 1 / √(1 + (εr)²)
+        InverseMultiquadric,
+    }
+
+    impl RadialBasisKernel {
+        /// Names accepted by `from_name`
+        pub const NAMES: &'static [&'static str] = &["thin-plate", "gaussian", "multiquadric", "inverse-multiquadric"];
+
+        pub fn from_name(name: &str) -> Option<RadialBasisKernel> {
+            match name {
+                "thin-plate" => Some(RadialBasisKernel::ThinPlate),
+                "gaussian" => Some(RadialBasisKernel::Gaussian),
+                "multiquadric" => Some(RadialBasisKernel::Multiquadric),
+                "inverse-multiquadric" => Some(RadialBasisKernel::InverseMultiquadric),
+                _ => None,
+            }
+        }
+
+        pub fn evaluate(&self, r: f64, shape: f64) -> f64 {
+            match *self {
+                RadialBasisKernel::ThinPlate => if r == 0.0 { 0.0 } else { r * r * r.ln() },
+                RadialBasisKernel::Gaussian => (-(shape * r).powi(2)).exp(),
+                RadialBasisKernel::Multiquadric => (1.0 + (shape * r).powi(2)).sqrt(),
+                RadialBasisKernel::InverseMultiquadric => 1.0 / (1.0 + (shape * r).powi(2)).sqrt(),
+            }
+        }
+    }
+
+    /// Error returned when the spline can't be fitted through the control points
+    #[derive(Clone, Debug, PartialEq, Eq)]
+    pub enum SplineError {
+        /// The affine part needs at least 3 control points
+        TooFewPoints(usize),
+        /// The system is singular, e.g. when control points are duplicated or collinear
+        Singular,
+    }
+
+    impl fmt::Display for SplineError {
+        fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+            match self {
+                SplineError::TooFewPoints(count) => write!(f, "found {} control points, need at least 3", count),
+                SplineError::Singular => write!(f, "the control points are duplicated or collinear"),
+            }
+        }
+    }
+
+    impl Error for SplineError {}
+
+    /// Thin-plate spline, or another radial basis function, through the control points.
+    /// Solved once and then evaluated anywhere, including outside the convex hull of the control points.
+    #[derive(Clone, Debug)]
+    pub struct ThinPlateSpline {
+        kernel: RadialBasisKernel,
+        shape: f64,
+        /// Physical (x,y) Coordinates of the control points
+        centres: Vec<cg::Point2<f64>>,
+        /// Weight of each control point, one column per Virtual Coordinate
+        weights: na::DMatrix<f64>,
+        /// Affine part (1, x, y), one column per Virtual Coordinate
+        affine: na::DMatrix<f64>,
+    }
+
+    impl ThinPlateSpline {
+        /// Fit the spline through the control points of the triangulation, with the shape parameter `shape`
+        /// of the kernel. `regularisation` is added to the diagonal of the system, 0 for exact interpolation.
+        /// Fails if the system is singular, e.g. when the control points are collinear.
+        pub fn new(
+            delaunay: &Delaunay,
+            kernel: RadialBasisKernel,
+            shape: f64,
+            regularisation: f64
+        ) -> Result<ThinPlateSpline, SplineError> {
+            let centres: Vec<cg::Point2<f64>> = delaunay.vertices().map(|v| v.position()).collect();
+            let n = centres.len();
+            if n < 3 {
+                return Err(SplineError::TooFewPoints(n));
+            }
+            //  Solve [K + λI, P; Pᵀ, 0] [w; a] = [v; 0] for both Virtual Coordinates at once
+            let mut system = na::DMatrix::<f64>::zeros(n + 3, n + 3);
+            let mut values = na::DMatrix::<f64>::zeros(n + 3, 2);
+            for (i, v) in delaunay.vertices().enumerate() {
+                for (j, centre) in centres.iter().enumerate() {
+                    system[(i, j)] = kernel.evaluate(v.position().distance(*centre), shape);
+                }
+                system[(i, i)] += regularisation;
+                let affine = [1.0, centres[i].x, centres[i].y];
+                for (k, &value) in affine.iter().enumerate() {
+                    system[(i, n + k)] = value;
+                    system[(n + k, i)] = value;
+                }
+                values[(i, 0)] = v.virtual_point.x;
+                values[(i, 1)] = v.virtual_point.y;
+            }
+            let solution = system.lu().solve(&values).ok_or(SplineError::Singular)?;
+            if !solution.iter().all(|value| value.is_finite()) {
+                return Err(SplineError::Singular);
+            }
+            Ok(ThinPlateSpline {
+                kernel,
+                shape,
+                centres,
+                weights: solution.rows(0, n).into_owned(),
+                affine: solution.rows(n, 3).into_owned(),
+            })
+        }
+
+        pub fn kernel(&self) -> RadialBasisKernel {
+            self.kernel
+        }
+
+        /// Return the Virtual (x,y) point at the Physical (x,y) point
+        pub fn evaluate(&self, point: cg::Point2<f64>) -> cg::Point2<f64> {
+            let mut result = [0.0; 2];
+            for (axis, value) in result.iter_mut().enumerate() {
+                *value = self.affine[(0, axis)] + self.affine[(1, axis)] * point.x + self.affine[(2, axis)] * point.y;
+                for (i, centre) in self.centres.iter().enumerate() {
+                    *value += self.weights[(i, axis)] * self.kernel.evaluate(point.distance(*centre), self.shape);
+                }
+            }
+            cg::Point2::new(result[0], result[1])
+        }
+    }
+
+    /// Thin-plate spline, or another radial basis function, through all control points.
+    /// The spline is solved when the method is created, so it interpolates the control points that it was created
+    /// with, whatever triangulation is passed to `interpolate`.
+    pub struct ThinPlateSplineInterpolation {
+        spline: ThinPlateSpline,
+    }
+
+    impl ThinPlateSplineInterpolation {
+        /// Solve the spline through the control points of the triangulation, see `ThinPlateSpline::new`
+        pub fn new(
+            delaunay: &Delaunay,
+            kernel: RadialBasisKernel,
+            shape: f64,
+            regularisation: f64
+        ) -> Result<ThinPlateSplineInterpolation, SplineError> {
+            let spline = ThinPlateSpline::new(delaunay, kernel, shape, regularisation)?;
+            Ok(ThinPlateSplineInterpolation { spline })
+        }
+
+        pub fn spline(&self) -> &ThinPlateSpline {
+            &self.spline
+        }
+    }
+
+    impl InterpolationMethod for ThinPlateSplineInterpolation {
+        fn interpolate(&self, _delaunay: &Delaunay, point: cg::Point2<f64>) -> cg::Point2<f64> {
+            self.spline.evaluate(point)
+        }
+
+        fn title(&self) -> &'static str {
+            match self.spline.kernel() {
+                RadialBasisKernel::ThinPlate => "thin-plate spline",
+                RadialBasisKernel::Gaussian => "gaussian radial basis function",
+                RadialBasisKernel::Multiquadric => "multiquadric radial basis function",
+                RadialBasisKernel::InverseMultiquadric => "inverse multiquadric radial basis function",
+            }
+        }
+    }
+
 }
 
 /*
  * Caches interpolated values on a grid and offers methods to
  * convert these into an edge list or a vertices / indices list
  */
-pub struct Grid<I: InterpolationMethod> {
-    grid: [[f64; X_PHYSICAL_SUBDIVISIONS + 1]; Y_PHYSICAL_SUBDIVISIONS + 1],
-    __interpolation: ::std::marker::PhantomData<I>,
+pub struct Grid {
+    grids: [Vec<Vec<f64>>; 2],  //  Indexed by VirtualAxis::index, then [y][x]
+    geometry: DisplayGeometry,
+    title: &'static str,  //  Title of the interpolation method
 }
 
-impl<I: InterpolationMethod> Grid<I> {
-    // Returns a list of edges for rendering
-    pub fn get_edges(&self) -> Vec<(na::Point3<f32>, na::Point3<f32>)> {
+impl Grid {
+    // Returns a list of edges for rendering, with the Virtual Coordinate on `axis` as the height
+    pub fn get_edges(&self, axis: VirtualAxis) -> Vec<(na::Point3<f32>, na::Point3<f32>)> {
+        let grid = self.values(axis);
         let mut result = Vec::new();
-        for y in 0..Y_PHYSICAL_SUBDIVISIONS {
-            for x in 0..X_PHYSICAL_SUBDIVISIONS {
-                let from_val = self.grid[y][x] + OFFSET;
-                let from_pos = super::transform_physical_point(cg::Point2::new(x as f64, y as f64));
-                let from = PointWithHeight::new(from_pos, from_val);
+        for y in 0..self.geometry.y_physical_subdivisions() {
+            for x in 0..self.geometry.x_physical_subdivisions() {
+                let from_val = grid[y][x] + OFFSET;
+                let from_pos = self.geometry.transform_physical_point(cg::Point2::new(x as f64, y as f64));
                 for &(to_x, to_y) in &[(x + 1, y), (x, y + 1)] {
-                    let to_val = self.grid[to_y][to_x] + OFFSET;
-                    let to_pos = super::transform_physical_point(cg::Point2::new(to_x as f64, to_y as f64));
-                    let to = PointWithHeight::new(to_pos, to_val);
+                    let to_val = grid[to_y][to_x] + OFFSET;
+                    let to_pos = self.geometry.transform_physical_point(cg::Point2::new(to_x as f64, to_y as f64));
                     result.push((
-                        cg_vec_to_na(from.position_3d().to_vec().cast().unwrap()),
-                        cg_vec_to_na(to.position_3d().to_vec().cast().unwrap()),
+                        cg_vec_to_na(cg::Vector3::new(from_pos.x, from_pos.y, from_val)),
+                        cg_vec_to_na(cg::Vector3::new(to_pos.x, to_pos.y, to_val)),
                     ));
                 }
             }
@@ -124,25 +521,29 @@ impl<I: InterpolationMethod> Grid<I> {
     }
 
     // Returns a list of vertices and a list of triangle indices that form the
-    // grid's mesh.
-    pub fn get_triangles(&self) -> (Vec<na::Point3<f32>>, Vec<na::Point3<u16>>) {
+    // grid's mesh, with the Virtual Coordinate on `axis` as the height.
+    #[allow(clippy::needless_range_loop)]
+    pub fn get_triangles(&self, axis: VirtualAxis) -> (Vec<na::Point3<f32>>, Vec<na::Point3<u32>>) {
+        let grid = self.values(axis);
         let mut vertices = Vec::new();
         let mut indices = Vec::new();
 
-        for y in 0..=Y_PHYSICAL_SUBDIVISIONS {
-            for x in 0..=X_PHYSICAL_SUBDIVISIONS {
-                let val = self.grid[y][x] + OFFSET;
-                let pos = super::transform_physical_point(cg::Point2::new(x as f64, y as f64));
+        let x_subdivisions = self.geometry.x_physical_subdivisions();
+        let y_subdivisions = self.geometry.y_physical_subdivisions();
+        for y in 0..=y_subdivisions {
+            for x in 0..=x_subdivisions {
+                let val = grid[y][x] + OFFSET;
+                let pos = self.geometry.transform_physical_point(cg::Point2::new(x as f64, y as f64));
                 vertices.push(na::Point3::new(pos.x as f32, pos.y as f32, val as f32));
             }
         }
-        for y in 0..Y_PHYSICAL_SUBDIVISIONS {
-            for x in 0..X_PHYSICAL_SUBDIVISIONS {
-                let index = |x, y| y * (X_PHYSICAL_SUBDIVISIONS + 1) + x;
-                let v00 = index(x, y) as u16;
-                let v10 = index(x + 1, y) as u16;
-                let v01 = index(x, y + 1) as u16;
-                let v11 = index(x + 1, y + 1) as u16;
+        for y in 0..y_subdivisions {
+            for x in 0..x_subdivisions {
+                let index = |x, y| y * (x_subdivisions + 1) + x;
+                let v00 = index(x, y) as u32;
+                let v10 = index(x + 1, y) as u32;
+                let v01 = index(x, y + 1) as u32;
+                let v11 = index(x + 1, y + 1) as u32;
                 indices.push(na::Point3::new(v00, v10, v11));
                 indices.push(na::Point3::new(v00, v11, v01));
             }
@@ -152,43 +553,259 @@ impl<I: InterpolationMethod> Grid<I> {
 
     // This will do the actual interpolation and store it in the triangulation
     #[allow(clippy::needless_range_loop)]
-    pub fn from_delaunay_interpolation(delaunay: &Delaunay) -> Grid<I> {
-        let mut values = [[0.0; X_PHYSICAL_SUBDIVISIONS + 1]; Y_PHYSICAL_SUBDIVISIONS + 1];
-        for y in 0..=Y_PHYSICAL_SUBDIVISIONS {
-            for x in 0..=X_PHYSICAL_SUBDIVISIONS {
-                let pos = super::transform_physical_point(cg::Point2::new(x as f64, y as f64));
-                let value = I::interpolate(delaunay, pos);
-                values[y][x] = value.floor();
-
-                //#[cfg(feature = "interpolate_x")]  //  If interpolating X values...
-                //println!("XPhysical={:.0}, YPhysical={:.0}, XVirtual={:.0}", pos.x, pos.y, value);
+    pub fn from_delaunay_interpolation<I: InterpolationMethod + ?Sized>(
+        method: &I,
+        delaunay: &Delaunay,
+        geometry: &DisplayGeometry
+    ) -> Grid {
+        let values = vec![vec![0.0; geometry.x_physical_subdivisions() + 1]; geometry.y_physical_subdivisions() + 1];
+        let mut grids = [values.clone(), values];
+        for y in 0..=geometry.y_physical_subdivisions() {
+            for x in 0..=geometry.x_physical_subdivisions() {
+                let pos = geometry.transform_physical_point(cg::Point2::new(x as f64, y as f64));
+                let value = method.interpolate(delaunay, pos);
+                grids[VirtualAxis::X.index()][y][x] = value.x.floor();
+                grids[VirtualAxis::Y.index()][y][x] = value.y.floor();
 
-                //#[cfg(feature = "interpolate_y")]  //  If interpolating X values...
-                //println!("XPhysical={:.0}, YPhysical={:.0}, YVirtual={:.0}", pos.x, pos.y, value);
+                //println!("XPhysical={:.0}, YPhysical={:.0}, XVirtual={:.0}, YVirtual={:.0}", pos.x, pos.y, value.x, value.y);
             }
         }
 
-        //  Dump out the grid
-        #[cfg(feature = "interpolate_x")]  //  If interpolating X values...
-        println!("X_VIRTUAL_GRID=\n");
-        
-        #[cfg(feature = "interpolate_y")]  //  If interpolating Y values...
-        println!("Y_VIRTUAL_GRID=\n");
+        Grid {
+            grids,
+            geometry: *geometry,
+            title: method.title(),
+        }
+    }
 
-        print!("[");
-        for row in values.iter() {
-            print!("[");
+    // Returns the interpolated Virtual Coordinates on `axis`, indexed by [y][x]
+    pub fn values(&self, axis: VirtualAxis) -> &[Vec<f64>] {
+        &self.grids[axis.index()]
+    }
+
+    pub fn geometry(&self) -> &DisplayGeometry {
+        &self.geometry
+    }
+
+    // Returns the title of the interpolation method
+    pub fn title(&self) -> &'static str {
+        self.title
+    }
+
+    // Dumps out the grid for `axis` so that it may be copied into data.rs
+    pub fn print(&self, axis: VirtualAxis) {
+        println!("{}=\n", axis.grid_name());
+        print!("&[");
+        for row in self.values(axis).iter() {
+            print!("&[");
             for val in row.iter() {
                 print!("{:.1},", val);
             }
             print!("],");
         }
         println!("]\n");
+    }
 
-        Grid {
-            grid: values,
-            __interpolation: Default::default(),
+    // Writes both grids as Rust statics, so that the output may be included as data.rs
+    pub fn write_statics<W: Write>(&self, out: &mut W) -> io::Result<()> {
+        for &axis in VirtualAxis::ALL.iter() {
+            writeln!(out, "/// Virtual {:?} Coordinates interpolated by {}, indexed by [y][x]", axis, self.title)?;
+            writeln!(out, "pub static {}: &[&[f64]] =", axis.grid_name())?;
+            write!(out, "&[")?;
+            for row in self.values(axis).iter() {
+                write!(out, "&[")?;
+                for val in row.iter() {
+                    write!(out, "{:.1},", val)?;
+                }
+                write!(out, "],")?;
+            }
+            writeln!(out, "]\n;\n")?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::interpolation_methods::{
+        BicubicLatticeInterpolation, CloughTocherInterpolation, InverseDistanceWeighting, RadialBasisKernel,
+        SplineError, ThinPlateSplineInterpolation,
+    };
+    use super::InterpolationMethod;
+    use crate::control_points::ControlPoint;
+    use crate::delaunay_creation::{builtin_control_points, triangulation_from_control_points, Delaunay};
+    use cgmath as cg;
+    use cgmath::{EuclideanSpace, InnerSpace};
+    use spade::delaunay::PositionInTriangulation;
+    use spade::HasPosition;
+
+    /// Triangulation of scattered points whose Virtual x and y are `value`, with the exact `gradients`
+    /// instead of the estimated ones
+    fn exact_triangulation(value: fn(f64, f64) -> (f64, f64), gradients: fn(f64, f64) -> [cg::Point2<f64>; 2]) -> Delaunay {
+        let positions = [
+            (0.0, 0.0), (50.0, 0.0), (100.0, 0.0), (0.0, 40.0), (45.0, 35.0), (100.0, 45.0),
+            (0.0, 100.0), (55.0, 100.0), (100.0, 100.0), (30.0, 70.0), (75.0, 65.0),
+        ];
+        let points: Vec<ControlPoint> = positions.iter().map(|&(x, y)| {
+            let (x_virtual, y_virtual) = value(x, y);
+            ControlPoint { x_physical: x, y_physical: y, x_virtual, y_virtual }
+        }).collect();
+        let mut delaunay = triangulation_from_control_points(&points);
+        for handle in 0..delaunay.num_vertices() {
+            let vertex = delaunay.vertex_mut(handle);
+            let position = vertex.position();
+            vertex.gradients = gradients(position.x, position.y);
+        }
+        delaunay
+    }
+
+    /// Check that Clough-Tocher reproduces `value` inside every triangle, including around the centroid
+    /// where the point moves between the three patches
+    fn assert_clough_tocher_reproduces(delaunay: &Delaunay, value: fn(f64, f64) -> (f64, f64)) {
+        let weights = [
+            (1.0 / 3.0, 1.0 / 3.0, 1.0 / 3.0), (0.6, 0.2, 0.2), (0.2, 0.5, 0.3), (0.1, 0.1, 0.8), (0.45, 0.45, 0.1),
+            (1.0 / 3.0 + 1e-6, 1.0 / 3.0, 1.0 / 3.0 - 1e-6), (1.0 / 3.0 - 1e-6, 1.0 / 3.0 + 1e-6, 1.0 / 3.0),
+        ];
+        for triangle in delaunay.triangles() {
+            let vs = triangle.as_triangle();
+            let (a, b, c) = (vs[0].position().to_vec(), vs[1].position().to_vec(), vs[2].position().to_vec());
+            for &(wa, wb, wc) in weights.iter() {
+                let point = cg::Point2::from_vec(a * wa + b * wb + c * wc);
+                let interpolated = CloughTocherInterpolation.interpolate(delaunay, point);
+                let (x_virtual, y_virtual) = value(point.x, point.y);
+                assert!(
+                    (interpolated.x - x_virtual).abs() < 1e-6 && (interpolated.y - y_virtual).abs() < 1e-6,
+                    "{:?}: expected ({}, {}), found {:?}", point, x_virtual, y_virtual, interpolated
+                );
+            }
         }
     }
 
+    #[test]
+    fn clough_tocher_reproduces_linear_data() {
+        let value = |x: f64, y: f64| (2.0 * x - 3.0 * y + 5.0, -0.5 * x + 0.25 * y);
+        let gradients = |_: f64, _: f64| [cg::Point2::new(2.0, -3.0), cg::Point2::new(-0.5, 0.25)];
+        assert_clough_tocher_reproduces(&exact_triangulation(value, gradients), value);
+    }
+
+    #[test]
+    fn clough_tocher_reproduces_quadratic_data() {
+        let value = |x: f64, y: f64| (0.01 * x * x - 0.02 * x * y + 0.005 * y * y + x, 0.003 * x * y - 0.01 * y * y + 2.0 * y);
+        let gradients = |x: f64, y: f64| [
+            cg::Point2::new(0.02 * x - 0.02 * y + 1.0, -0.02 * x + 0.01 * y),
+            cg::Point2::new(0.003 * y, 0.003 * x - 0.02 * y + 2.0),
+        ];
+        assert_clough_tocher_reproduces(&exact_triangulation(value, gradients), value);
+    }
+
+    #[test]
+    fn clough_tocher_agrees_across_edges() {
+        let delaunay = triangulation_from_control_points(&builtin_control_points());
+        for edge in delaunay.edges() {
+            let (from, to) = (edge.from().position(), edge.to().position());
+            let tangent = to - from;
+            let normal = cg::Vector2::new(-tangent.y, tangent.x).normalize();
+            for &s in [0.25, 0.5, 0.8].iter() {
+                let point = from + tangent * s;
+                let left = CloughTocherInterpolation.interpolate(&delaunay, point + normal * 1e-9);
+                let right = CloughTocherInterpolation.interpolate(&delaunay, point - normal * 1e-9);
+                assert!((left - right).magnitude() < 1e-6, "{:?}: {:?} and {:?}", point, left, right);
+
+                //  C1 inside the convex hull: the derivative normal to the edge is the same on both sides.
+                //  The step is small, since the patches of the slivers along the hull curve sharply.
+                let step = normal * 1e-6;
+                let inside = |point| matches!(delaunay.locate(&point), PositionInTriangulation::InTriangle(_));
+                if !(inside(point + step) && inside(point - step)) { continue; }
+                let centre = CloughTocherInterpolation.interpolate(&delaunay, point);
+                let left = (CloughTocherInterpolation.interpolate(&delaunay, point + step) - centre) / 1e-6;
+                let right = (centre - CloughTocherInterpolation.interpolate(&delaunay, point - step)) / 1e-6;
+                assert!((left - right).magnitude() < 1e-3, "{:?}: derivatives {:?} and {:?}", point, left, right);
+            }
+        }
+    }
+
+    #[test]
+    fn spline_fails_to_fit_collinear_points() {
+        let points: Vec<ControlPoint> = (0..4).map(|i| ControlPoint {
+            x_physical: 10.0 * i as f64,
+            y_physical: 5.0 * i as f64,
+            x_virtual: i as f64,
+            y_virtual: 0.0,
+        }).collect();
+        let delaunay = triangulation_from_control_points(&points);
+        let spline = ThinPlateSplineInterpolation::new(&delaunay, RadialBasisKernel::ThinPlate, 0.02, 0.0);
+        assert_eq!(spline.err(), Some(SplineError::Singular));
+    }
+
+    #[test]
+    fn spline_passes_through_control_points() {
+        let points = builtin_control_points();
+        let delaunay = triangulation_from_control_points(&points);
+        let spline = ThinPlateSplineInterpolation::new(&delaunay, RadialBasisKernel::ThinPlate, 0.02, 0.0).unwrap();
+        for point in points.iter() {
+            let value = spline.interpolate(&delaunay, cg::Point2::new(point.x_physical, point.y_physical));
+            assert!((value.x - point.x_virtual).abs() < 1e-9 && (value.y - point.y_virtual).abs() < 1e-9);
+        }
+    }
+
+    /// Control points at Physical (0,0), (3,0) and (0,100), with Virtual x 0, 30 and 90
+    fn idw_triangulation() -> Delaunay {
+        let points = [(0.0, 0.0, 0.0), (3.0, 0.0, 30.0), (0.0, 100.0, 90.0)];
+        triangulation_from_control_points(&points.iter().map(|&(x_physical, y_physical, x_virtual)| ControlPoint {
+            x_physical, y_physical, x_virtual, y_virtual: 0.0,
+        }).collect::<Vec<_>>())
+    }
+
+    #[test]
+    fn idw_returns_control_points_exactly() {
+        let delaunay = idw_triangulation();
+        let idw = InverseDistanceWeighting { power: 2.0, neighbours: 0 };
+        assert_eq!(idw.interpolate(&delaunay, cg::Point2::new(3.0, 0.0)), cg::Point2::new(30.0, 0.0));
+    }
+
+    #[test]
+    fn idw_weights_by_power_of_distance() {
+        //  At (1,0), the two nearest control points are at distances 1 and 2, so the weights are 1 and 1 / 2 ^ power
+        let delaunay = idw_triangulation();
+        let point = cg::Point2::new(1.0, 0.0);
+        for &(power, expected) in [(1.0, 30.0 * 0.5 / 1.5), (2.0, 30.0 * 0.25 / 1.25)].iter() {
+            let value = InverseDistanceWeighting { power, neighbours: 2 }.interpolate(&delaunay, point);
+            assert!((value.x - expected).abs() < 1e-12, "power {}: {}", power, value.x);
+        }
+    }
+
+    #[test]
+    fn idw_uses_only_the_nearest_neighbours() {
+        let delaunay = idw_triangulation();
+        let point = cg::Point2::new(1.0, 0.0);
+        let nearest = InverseDistanceWeighting { power: 1.0, neighbours: 2 }.interpolate(&delaunay, point);
+        let all = InverseDistanceWeighting { power: 1.0, neighbours: 0 }.interpolate(&delaunay, point);
+        assert_eq!(all, InverseDistanceWeighting { power: 1.0, neighbours: 3 }.interpolate(&delaunay, point));
+        assert!(all.x > nearest.x, "the far control point pulls towards 90: {} and {}", all.x, nearest.x);
+    }
+
+    #[test]
+    fn idw_large_power_does_not_underflow() {
+        //  30 ^ 1000 overflows, so 1 / distance ^ power would be 0 for every control point.
+        //  The nearest control point, at (3,0), dominates instead.
+        let delaunay = idw_triangulation();
+        let value = InverseDistanceWeighting { power: 1000.0, neighbours: 0 }.interpolate(&delaunay, cg::Point2::new(2.9, 30.0));
+        assert!((value.x - 30.0).abs() < 0.5, "{:?}", value);
+    }
+
+    #[test]
+    fn bicubic_reports_missing_lattice() {
+        let delaunay = triangulation_from_control_points(&builtin_control_points());
+        let bicubic = BicubicLatticeInterpolation::new(&delaunay, None).unwrap();
+        assert!(bicubic.control_lattice().is_some());
+        assert_eq!(bicubic.title(), "bicubic lattice interpolation");
+        assert!(BicubicLatticeInterpolation::new(&delaunay, Some((8, 2))).is_err());
+
+        let mut points = builtin_control_points();
+        points[5].x_virtual += 0.5;
+        let delaunay = triangulation_from_control_points(&points);
+        let bicubic = BicubicLatticeInterpolation::new(&delaunay, None).unwrap();
+        assert!(bicubic.control_lattice().is_none());
+        assert!(bicubic.title().starts_with("natural neighbor"));
+    }
 }
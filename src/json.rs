@@ -0,0 +1,157 @@
+//! Machine-readable dump of everything that `generate` computes, for tooling and dashboards.
+//! Contains the generation parameters, the control points, the triangulation edges, the grids of every
+//! interpolation method and both maps for the selected method.
+use crate::control_points::{ControlPoint, VirtualAxis};
+use crate::delaunay_creation::{Delaunay, PointWithVirtual};
+use crate::export::MapSource;
+use crate::geometry::DisplayGeometry;
+use crate::interpolation::{Grid, InterpolationMethod};
+use crate::mapping::{MapLayout, PixelMaps};
+use serde_json;
+use spade::HasPosition;
+use std::io::{self, Write};
+
+/// All the artifacts of one run of `generate`
+#[derive(Serialize)]
+pub struct Artifacts {
+    pub parameters: Parameters,
+    pub control_points: Vec<ControlPoint>,
+    /// Edges of the triangulation, each as the control points at its ends
+    pub edges: Vec<Edge>,
+    /// Interpolated Virtual (x,y) grids for each interpolation method
+    pub grids: Vec<MethodGrids>,
+    /// Maps for the selected interpolation method
+    pub maps: PixelMaps,
+}
+
+/// How the artifacts were generated
+#[derive(Serialize)]
+pub struct Parameters {
+    pub version: &'static str,
+    /// Title of the interpolation method used for the maps
+    pub method: String,
+    /// Where the control points came from, e.g. "built-in" or the CSV path
+    pub control_points: String,
+    pub layout: MapLayout,
+    pub geometry: DisplayGeometry,
+}
+
+/// Edge of the triangulation between two control points
+#[derive(Serialize)]
+pub struct Edge {
+    pub from: ControlPoint,
+    pub to: ControlPoint,
+}
+
+/// Interpolated Virtual (x,y) grids for one interpolation method, indexed by [y][x]
+#[derive(Serialize)]
+pub struct MethodGrids {
+    pub method: &'static str,
+    pub x_virtual_grid: Vec<Vec<f64>>,
+    pub y_virtual_grid: Vec<Vec<f64>>,
+}
+
+impl Artifacts {
+    /// Interpolate the grids with each of `methods`, e.g. `MethodParameters::all_methods`, and the maps with `method`
+    pub fn new(
+        method: &dyn InterpolationMethod,
+        methods: &[Box<dyn InterpolationMethod>],
+        delaunay: &Delaunay,
+        geometry: &DisplayGeometry,
+        layout: MapLayout,
+        source: &MapSource
+    ) -> Artifacts {
+        let grids: Vec<Grid> = methods.iter()
+            .map(|method| Grid::from_delaunay_interpolation(&**method, delaunay, geometry))
+            .collect();
+        //  Reuse the grid of the selected method if it's one of `methods`
+        let maps = match grids.iter().find(|grid| grid.title() == method.title()) {
+            Some(grid) => PixelMaps::new(geometry, grid.values(VirtualAxis::X), grid.values(VirtualAxis::Y), layout),
+            None => {
+                let grid = Grid::from_delaunay_interpolation(method, delaunay, geometry);
+                PixelMaps::new(geometry, grid.values(VirtualAxis::X), grid.values(VirtualAxis::Y), layout)
+            }
+        };
+        Artifacts {
+            parameters: Parameters {
+                version: env!("CARGO_PKG_VERSION"),
+                method: source.method.to_string(),
+                control_points: source.control_points.to_string(),
+                layout,
+                geometry: *geometry,
+            },
+            control_points: delaunay.vertices().map(|v| control_point(&v)).collect(),
+            edges: delaunay.edges().map(|e| Edge { from: control_point(&e.from()), to: control_point(&e.to()) }).collect(),
+            grids: grids.iter().map(method_grids).collect(),
+            maps,
+        }
+    }
+
+    /// Write the artifacts as JSON
+    pub fn write<W: Write + ?Sized>(&self, out: &mut W) -> io::Result<()> {
+        serde_json::to_writer(&mut *out, self)?;
+        writeln!(out)
+    }
+}
+
+fn method_grids(grid: &Grid) -> MethodGrids {
+    MethodGrids {
+        method: grid.title(),
+        x_virtual_grid: grid.values(VirtualAxis::X).to_vec(),
+        y_virtual_grid: grid.values(VirtualAxis::Y).to_vec(),
+    }
+}
+
+fn control_point(vertex: &PointWithVirtual) -> ControlPoint {
+    let position = vertex.position();
+    ControlPoint {
+        x_physical: position.x,
+        y_physical: position.y,
+        x_virtual: vertex.virtual_point.x,
+        y_virtual: vertex.virtual_point.y,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::delaunay_creation::{builtin_control_points, triangulation_from_control_points};
+    use crate::interpolation::interpolation_methods::{BarycentricInterpolation, NaturalNeighborInterpolation};
+    use serde_json::Value;
+
+    #[test]
+    fn json_contains_the_grids_maps_and_parameters() {
+        let delaunay = triangulation_from_control_points(&builtin_control_points());
+        let geometry = DisplayGeometry::default();
+        let methods: Vec<Box<dyn InterpolationMethod>> =
+            vec![Box::new(BarycentricInterpolation), Box::new(NaturalNeighborInterpolation)];
+        let source = MapSource { method: NaturalNeighborInterpolation.title(), control_points: "built-in" };
+        let artifacts = Artifacts::new(
+            &NaturalNeighborInterpolation, &methods, &delaunay, &geometry, MapLayout::Quadrant, &source
+        );
+        let mut out = Vec::new();
+        artifacts.write(&mut out).unwrap();
+        let json: Value = serde_json::from_slice(&out).unwrap();
+
+        assert_eq!(json["parameters"]["method"], NaturalNeighborInterpolation.title());
+        assert_eq!(json["parameters"]["control_points"], "built-in");
+        assert!(json["parameters"]["geometry"].is_object());
+        assert_eq!(json["control_points"].as_array().unwrap().len(), delaunay.num_vertices());
+        assert_eq!(json["edges"].as_array().unwrap().len(), delaunay.num_edges());
+
+        let grids = json["grids"].as_array().unwrap();
+        assert_eq!(grids.len(), methods.len());
+        for (grid, method) in grids.iter().zip(methods.iter()) {
+            assert_eq!(grid["method"], method.title());
+            for axis in ["x_virtual_grid", "y_virtual_grid"].iter() {
+                let rows = grid[*axis].as_array().unwrap();
+                assert_eq!(rows.len(), geometry.y_physical_subdivisions() + 1);
+                assert_eq!(rows[0].as_array().unwrap().len(), geometry.x_physical_subdivisions() + 1);
+            }
+        }
+
+        let maps = &json["maps"];
+        assert_eq!(maps["physical_to_virtual"], serde_json::to_value(&artifacts.maps.physical_to_virtual).unwrap());
+        assert_eq!(maps["virtual_to_physical"], serde_json::to_value(&artifacts.maps.virtual_to_physical).unwrap());
+    }
+}
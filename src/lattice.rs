@@ -0,0 +1,257 @@
+//! Lattice of control points, for interpolating over its quads instead of the Delaunay triangles.
+//! The control points are usually a W x H lattice: each row shares a Virtual y and each column shares a Virtual x,
+//! while their Physical (x,y) positions are curved. Triangulating them as scattered data gives diagonal artefacts
+//! that depend on the Delaunay flips, so here the lattice is treated as a Catmull-Rom surface P(u,v) over the
+//! lattice indices, and P(u,v) is inverted with Newton's method to find the lattice position of a Physical point.
+use crate::delaunay_creation::Delaunay;
+use cgmath as cg;
+use cgmath::{EuclideanSpace, InnerSpace};
+use spade::HasPosition;
+
+/// Virtual Coordinates that differ by less than this belong to the same row or column
+const TOLERANCE: f64 = 1e-6;
+
+/// Newton's method converges when the step is smaller than `TOLERANCE`, and fails after this many iterations
+const MAX_ITERATIONS: usize = 32;
+
+/// W x H lattice of control points, indexed by [row][column]
+#[derive(Clone, Debug)]
+pub struct ControlLattice {
+    /// Virtual x of each column, increasing
+    pub x_virtual: Vec<f64>,
+    /// Virtual y of each row, increasing
+    pub y_virtual: Vec<f64>,
+    /// Physical (x,y) Coordinates of the control points, indexed by [row][column]
+    pub physical: Vec<Vec<cg::Point2<f64>>>,
+}
+
+impl ControlLattice {
+    /// Detect the lattice from the distinct Virtual x and Virtual y of the control points.
+    /// Returns `None` if the control points don't form a lattice.
+    pub fn detect(delaunay: &Delaunay) -> Option<ControlLattice> {
+        let distinct = |values: Vec<f64>| {
+            let mut values = values;
+            values.sort_by(|a, b| a.partial_cmp(b).unwrap());
+            values.dedup_by(|a, b| (*a - *b).abs() < TOLERANCE);
+            values.len()
+        };
+        let x_lattice = distinct(delaunay.vertices().map(|v| v.virtual_point.x).collect());
+        let y_lattice = distinct(delaunay.vertices().map(|v| v.virtual_point.y).collect());
+        ControlLattice::new(delaunay, x_lattice, y_lattice)
+    }
+
+    /// Arrange the control points into a lattice of `x_lattice` columns and `y_lattice` rows, e.g. from `--bicubic-lattice`.
+    /// Returns `None` if the control points don't fill the lattice, with a shared Virtual x per column
+    /// and a shared Virtual y per row.
+    pub fn new(delaunay: &Delaunay, x_lattice: usize, y_lattice: usize) -> Option<ControlLattice> {
+        if x_lattice < 2 || y_lattice < 2 || delaunay.num_vertices() != x_lattice * y_lattice {
+            return None;
+        }
+        let mut points: Vec<(cg::Point2<f64>, cg::Point2<f64>)> = delaunay.vertices()
+            .map(|v| (v.position(), v.virtual_point))
+            .collect();
+        //  Split into rows by Virtual y, then sort each row by Virtual x. Sorting by (y, x) at once would
+        //  mix up the columns of a row whose Virtual y differ within `TOLERANCE`.
+        points.sort_by(|a, b| a.1.y.partial_cmp(&b.1.y).unwrap());
+        for row in points.chunks_mut(x_lattice) {
+            row.sort_by(|a, b| a.1.x.partial_cmp(&b.1.x).unwrap());
+        }
+        let rows: Vec<&[(cg::Point2<f64>, cg::Point2<f64>)]> = points.chunks(x_lattice).collect();
+
+        let x_virtual: Vec<f64> = rows[0].iter().map(|(_, virtual_point)| virtual_point.x).collect();
+        let y_virtual: Vec<f64> = rows.iter().map(|row| row[0].1.y).collect();
+        for (row, &y) in rows.iter().zip(y_virtual.iter()) {
+            for (&(_, virtual_point), &x) in row.iter().zip(x_virtual.iter()) {
+                if (virtual_point.x - x).abs() > TOLERANCE || (virtual_point.y - y).abs() > TOLERANCE {
+                    return None;
+                }
+            }
+        }
+        let increasing = |values: &[f64]| values.windows(2).all(|pair| pair[1] - pair[0] > TOLERANCE);
+        if !increasing(&x_virtual) || !increasing(&y_virtual) {
+            return None;
+        }
+        let physical = rows.iter().map(|row| row.iter().map(|&(position, _)| position).collect()).collect();
+        Some(ControlLattice { x_virtual, y_virtual, physical })
+    }
+
+    /// Return the Virtual (x,y) point at the Physical (x,y) point, or `None` if it can't be located
+    pub fn interpolate(&self, point: cg::Point2<f64>) -> Option<cg::Point2<f64>> {
+        let (u, v) = self.locate(point)?;
+        Some(cg::Point2::new(catmull_rom_1d(&self.x_virtual, u), catmull_rom_1d(&self.y_virtual, v)))
+    }
+
+    /// Find the lattice position (u,v), i.e. fractional column and row, of the Physical (x,y) point.
+    /// Returns `None` if Newton's method doesn't converge, e.g. where the surface folds over itself.
+    pub fn locate(&self, point: cg::Point2<f64>) -> Option<(f64, f64)> {
+        //  Start from the nearest control point
+        let mut best = (0.0, 0.0, f64::MAX);
+        for (row, points) in self.physical.iter().enumerate() {
+            for (column, position) in points.iter().enumerate() {
+                let distance = (*position - point).magnitude2();
+                if distance < best.2 { best = (column as f64, row as f64, distance); }
+            }
+        }
+        let (mut u, mut v) = (best.0, best.1);
+
+        //  Newton's method on P(u,v) - point = 0
+        for _ in 0..MAX_ITERATIONS {
+            let (position, du, dv) = self.surface(u, v);
+            let residual = point - position;
+            let determinant = du.x * dv.y - du.y * dv.x;
+            if determinant.abs() < f64::EPSILON { return None; }
+            let step_u = (residual.x * dv.y - residual.y * dv.x) / determinant;
+            let step_v = (du.x * residual.y - du.y * residual.x) / determinant;
+            u += step_u;
+            v += step_v;
+            if !(u.is_finite() && v.is_finite()) { return None; }
+            if step_u.abs() < TOLERANCE && step_v.abs() < TOLERANCE { return Some((u, v)); }
+        }
+        None
+    }
+
+    /// Return the Catmull-Rom surface P(u,v) and its derivatives with respect to u and v
+    fn surface(&self, u: f64, v: f64) -> (cg::Point2<f64>, cg::Vector2<f64>, cg::Vector2<f64>) {
+        let (columns, rows) = (self.x_virtual.len(), self.y_virtual.len());
+        let (i, s) = segment(u, columns);
+        let (j, t) = segment(v, rows);
+        let (weights_u, derivatives_u) = (catmull_rom_weights(s), catmull_rom_derivatives(s));
+        let (weights_v, derivatives_v) = (catmull_rom_weights(t), catmull_rom_derivatives(t));
+        let mut position = cg::Vector2::new(0.0, 0.0);
+        let mut du = cg::Vector2::new(0.0, 0.0);
+        let mut dv = cg::Vector2::new(0.0, 0.0);
+        for b in 0..4 {
+            for a in 0..4 {
+                let control = self.control_point(i + a as i64 - 1, j + b as i64 - 1);
+                position += control * (weights_u[a] * weights_v[b]);
+                du += control * (derivatives_u[a] * weights_v[b]);
+                dv += control * (weights_u[a] * derivatives_v[b]);
+            }
+        }
+        (cg::Point2::from_vec(position), du, dv)
+    }
+
+    /// Physical (x,y) Coordinates of the control point at (column, row). Beyond the edges of the lattice,
+    /// the control points are extended linearly, so that the Catmull-Rom surface is defined up to the edges.
+    fn control_point(&self, column: i64, row: i64) -> cg::Vector2<f64> {
+        let (columns, rows) = (self.x_virtual.len() as i64, self.y_virtual.len() as i64);
+        if column < 0 {
+            self.control_point(0, row) * 2.0 - self.control_point(1, row)
+        } else if column >= columns {
+            self.control_point(columns - 1, row) * 2.0 - self.control_point(columns - 2, row)
+        } else if row < 0 {
+            self.control_point(column, 0) * 2.0 - self.control_point(column, 1)
+        } else if row >= rows {
+            self.control_point(column, rows - 1) * 2.0 - self.control_point(column, rows - 2)
+        } else {
+            self.physical[row as usize][column as usize].to_vec()
+        }
+    }
+}
+
+/// Split the lattice position into the segment index, from 0 to `count - 2`, and the position within it.
+/// Positions beyond the ends extrapolate the first or last segment.
+fn segment(position: f64, count: usize) -> (i64, f64) {
+    let index = (position.floor() as i64).clamp(0, count as i64 - 2);
+    (index, position - index as f64)
+}
+
+/// Catmull-Rom weights of the 4 control points around a segment, at `t` from 0 to 1
+fn catmull_rom_weights(t: f64) -> [f64; 4] {
+    let (t2, t3) = (t * t, t * t * t);
+    [
+        0.5 * (-t3 + 2.0 * t2 - t),
+        0.5 * (3.0 * t3 - 5.0 * t2 + 2.0),
+        0.5 * (-3.0 * t3 + 4.0 * t2 + t),
+        0.5 * (t3 - t2),
+    ]
+}
+
+/// Derivatives of `catmull_rom_weights` with respect to `t`
+fn catmull_rom_derivatives(t: f64) -> [f64; 4] {
+    let t2 = t * t;
+    [
+        0.5 * (-3.0 * t2 + 4.0 * t - 1.0),
+        0.5 * (9.0 * t2 - 10.0 * t),
+        0.5 * (-9.0 * t2 + 8.0 * t + 1.0),
+        0.5 * (3.0 * t2 - 2.0 * t),
+    ]
+}
+
+/// Catmull-Rom curve through `values` at the fractional index `position`, extended linearly beyond the ends
+fn catmull_rom_1d(values: &[f64], position: f64) -> f64 {
+    let count = values.len() as i64;
+    let value = |index: i64| {
+        if index < 0 {
+            2.0 * values[0] - values[1]
+        } else if index >= count {
+            2.0 * values[count as usize - 1] - values[count as usize - 2]
+        } else {
+            values[index as usize]
+        }
+    };
+    let (i, t) = segment(position, values.len());
+    catmull_rom_weights(t).iter().enumerate().map(|(a, weight)| weight * value(i + a as i64 - 1)).sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::delaunay_creation::{builtin_control_points, triangulation_from_control_points};
+
+    fn builtin_lattice() -> ControlLattice {
+        ControlLattice::detect(&triangulation_from_control_points(&builtin_control_points())).unwrap()
+    }
+
+    #[test]
+    fn patches_reproduce_control_points() {
+        let lattice = builtin_lattice();
+        assert_eq!((lattice.x_virtual.len(), lattice.y_virtual.len()), (4, 4));
+        for point in builtin_control_points().iter() {
+            let value = lattice.interpolate(cg::Point2::new(point.x_physical, point.y_physical)).unwrap();
+            assert!(
+                (value.x - point.x_virtual).abs() < 1e-6 && (value.y - point.y_virtual).abs() < 1e-6,
+                "{:?} interpolates to {:?}", point, value
+            );
+        }
+    }
+
+    #[test]
+    fn surface_is_continuous_across_quad_edges() {
+        let lattice = builtin_lattice();
+        let epsilon = 1e-7;
+        let steps: Vec<f64> = (0..=12).map(|step| step as f64 * 0.25).collect();
+        for edge in 1..3 {
+            for &along in steps.iter() {
+                //  Both sides of the edge between columns, then between rows
+                for &(u, v, vertical) in [(edge as f64, along, true), (along, edge as f64, false)].iter() {
+                    let (position, du, dv) = lattice.surface(u, v);
+                    let across = if vertical { du } else { dv };
+                    let (before, _, _) = if vertical { lattice.surface(u - epsilon, v) } else { lattice.surface(u, v - epsilon) };
+                    let (after, _, _) = if vertical { lattice.surface(u + epsilon, v) } else { lattice.surface(u, v + epsilon) };
+                    assert!((after - before).magnitude() < 1e-5, "P jumps at ({}, {})", u, v);
+
+                    let before = lattice.interpolate(position - across * epsilon).unwrap();
+                    let after = lattice.interpolate(position + across * epsilon).unwrap();
+                    assert!((after - before).magnitude() < 1e-5, "Virtual point jumps at ({}, {})", u, v);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn detects_rows_within_tolerance() {
+        //  Nudge the first point of each row within the tolerance, so it sorts after the rest of its row by exact y
+        let mut points = builtin_control_points();
+        let mut nudged = Vec::new();
+        for point in points.iter_mut() {
+            if !nudged.contains(&point.y_virtual.to_bits()) {
+                nudged.push(point.y_virtual.to_bits());
+                point.y_virtual += TOLERANCE / 2.0;
+            }
+        }
+        let lattice = ControlLattice::detect(&triangulation_from_control_points(&points)).unwrap();
+        assert_eq!((lattice.x_virtual.len(), lattice.y_virtual.len()), (4, 4));
+        assert!(lattice.x_virtual.windows(2).all(|pair| pair[0] < pair[1]));
+    }
+}
@@ -0,0 +1,119 @@
+// Copyright 2017 The Spade Developers.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Interpolate CHIP-8's Emulator Screen (square) to PineTime Display (spherical).
+/*
+ * This library holds the interpolation engine and the map generators.
+ * It doesn't depend on kiss3d, so it may be called from build tools
+ * without opening a window. The kiss3d demo lives in ./viewer.rs and is
+ * built only when the `viewer` feature is enabled.
+ */
+#![warn(clippy::all)]
+extern crate cgmath;
+extern crate nalgebra;
+extern crate noise;
+extern crate png;
+extern crate rand;
+extern crate serde;
+#[macro_use]
+extern crate serde_derive;
+extern crate serde_json;
+extern crate spade;
+
+pub mod constants;
+pub mod control_points;
+pub mod coverage;
+pub mod data;
+pub mod delaunay_creation;
+pub mod export;
+pub mod geometry;
+pub mod gpu;
+pub mod interpolation;
+pub mod json;
+pub mod lattice;
+pub mod lut;
+pub mod mapping;
+pub mod mesh;
+pub mod parameters;
+pub mod projection;
+pub mod rectangles;
+pub mod render;
+pub mod spans;
+pub mod svg;
+
+use nalgebra as na;
+
+use cgmath as cg;
+use cgmath::EuclideanSpace;
+
+use crate::delaunay_creation::Delaunay;
+
+pub use crate::control_points::{load_control_points, ControlPoint, ControlPointError, VirtualAxis};
+pub use crate::coverage::{Coverage, CoverageMap};
+pub use crate::delaunay_creation::{
+    builtin_control_points, generate_random_triangulation, triangulation_from_control_points,
+    PointWithVirtual,
+};
+pub use crate::export::{write_c_header, write_rust_maps, MapSource};
+pub use crate::geometry::{DisplayGeometry, GeometryError};
+pub use crate::interpolation::{Grid, InterpolationMethod};
+pub use crate::json::Artifacts;
+pub use crate::lattice::ControlLattice;
+pub use crate::lut::{LookupTable, LutError};
+pub use crate::mapping::{get_bounding_box, BoundingBox, MapLayout, PixelMaps};
+pub use crate::mesh::{Mesh, MeshFormat};
+pub use crate::parameters::{MethodParameters, ParameterError};
+pub use crate::projection::SphericalProjection;
+pub use crate::rectangles::{OverdrawReport, RectangleCover};
+pub use crate::render::{render_heatmap, Image};
+pub use crate::svg::write_svg_overlay;
+pub use crate::spans::{decode_spans, encode_spans, Span, SpanReport};
+
+pub fn cg_vec_to_na(vec: cg::Vector3<f64>) -> na::Point3<f32> {
+    na::Point3::new(vec.x as f32, vec.y as f32, vec.z as f32)
+}
+
+/// Returns the normals of the triangulation, with the Virtual Coordinate on `axis` as the height
+pub fn get_normals(delaunay: &Delaunay, axis: VirtualAxis) -> Vec<(na::Point3<f32>, na::Point3<f32>)> {
+    let mut result = Vec::new();
+    for v in delaunay.vertices() {
+        let n = v.normal(axis);
+        let p = v.position_3d(axis);
+        result.push((cg_vec_to_na(p.to_vec()), cg_vec_to_na(p.to_vec() - n * 0.3)));
+    }
+    result
+}
+
+/// Returns the vertices and triangle indices of the triangulation, with the Virtual Coordinate on `axis` as the height
+pub fn get_triangulation_triangles(delaunay: &Delaunay, axis: VirtualAxis) -> (Vec<na::Point3<f32>>, Vec<na::Point3<u32>>) {
+    let mut coords = Vec::new();
+    let mut faces = Vec::new();
+    for vertex in delaunay.vertices() {
+        coords.push(cg_vec_to_na(vertex.position_3d(axis).to_vec()));
+    }
+    for triangle in delaunay.triangles() {
+        let triangle = triangle.as_triangle();
+        let h0 = triangle[0].fix();
+        let h1 = triangle[1].fix();
+        let h2 = triangle[2].fix();
+        faces.push(na::Point3::new(h0 as u32, h1 as u32, h2 as u32));
+    }
+    (coords, faces)
+}
+
+/// Returns the edges of the triangulation, with the Virtual Coordinate on `axis` as the height
+pub fn extract_edges(delaunay: &Delaunay, axis: VirtualAxis) -> Vec<(na::Point3<f32>, na::Point3<f32>)> {
+    let offset = cg::Vector3::new(0., 0., -0.01);
+    let mut lines = Vec::new();
+    for edge in delaunay.edges() {
+        let from_pos = cg_vec_to_na(edge.from().position_3d(axis).to_vec() + offset);
+        let to_pos = cg_vec_to_na(edge.to().position_3d(axis).to_vec() + offset);
+        lines.push((from_pos, to_pos));
+    }
+    lines
+}
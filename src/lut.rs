@@ -0,0 +1,391 @@
+//! Binary lookup table (LUT) file, to flash the grids and maps as a data blob.
+//! All numbers are little endian:
+//! ```text
+//! magic            4 bytes  "NNLT"
+//! version          u16      FORMAT_VERSION
+//! layout           u8       0 = Quadrant, 1 = FullScreen
+//! geometry         12 x f64 DisplayGeometry fields, in declaration order
+//! method           u16 length, then UTF-8 title of the interpolation method
+//! grid size        u16 width, u16 height
+//! X_VIRTUAL_GRID   width x height x f64, by rows
+//! Y_VIRTUAL_GRID   width x height x f64, by rows
+//! physical map     u16 width, u16 height, then (x, y) u8 pairs by rows
+//! virtual map      u16 width, u16 height, then (left, top, right, bottom) u8 by rows
+//! crc              u32      CRC-32 (IEEE) of all the bytes above
+//! ```
+//! The grids are stored as f64, so they round-trip losslessly.
+use crate::export::{physical_to_virtual_u8, virtual_to_physical_u8, ByteBoundingBox};
+use crate::geometry::DisplayGeometry;
+use crate::mapping::{MapLayout, PixelMaps};
+use std::error::Error;
+use std::fmt;
+use std::fs::{self, File};
+use std::io::{self, BufWriter, Write};
+use std::path::Path;
+
+/// Identifies a LUT file
+pub const MAGIC: [u8; 4] = *b"NNLT";
+
+/// Version of the format written by `LookupTable::write`
+pub const FORMAT_VERSION: u16 = 1;
+
+/// Grids and maps stored in a LUT file
+#[derive(Clone, Debug, PartialEq)]
+pub struct LookupTable {
+    pub layout: MapLayout,
+    pub geometry: DisplayGeometry,
+    /// Title of the interpolation method, e.g. "natural neighbor interpolation"
+    pub method: String,
+    /// Interpolated Virtual (x,y) Coordinates for the quadrant, indexed by [y][x]
+    pub x_virtual_grid: Vec<Vec<f64>>,
+    pub y_virtual_grid: Vec<Vec<f64>>,
+    /// Virtual (x,y) Pixel for each Physical Pixel, indexed by [y][x]
+    pub physical_to_virtual: Vec<Vec<(u8, u8)>>,
+    /// Bounding Box of the Physical Pixels for each Virtual Pixel, indexed by [y][x]
+    pub virtual_to_physical: Vec<Vec<ByteBoundingBox>>,
+}
+
+/// Error returned when a LUT file can't be read
+#[derive(Debug)]
+pub enum LutError {
+    /// The file couldn't be read
+    Io(io::Error),
+    /// The file doesn't start with `MAGIC`
+    Magic,
+    /// The file was written in a different version of the format than `FORMAT_VERSION`
+    Version(u16),
+    /// The CRC doesn't match the contents
+    Checksum { expected: u32, actual: u32 },
+    /// The contents are inconsistent, e.g. truncated or a table has the wrong size
+    Malformed(String),
+}
+
+impl fmt::Display for LutError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            LutError::Io(err) => write!(f, "failed to read lookup table: {}", err),
+            LutError::Magic => write!(f, "not a lookup table file"),
+            LutError::Version(version) => write!(f, "unsupported lookup table version {}, expected {}", version, FORMAT_VERSION),
+            LutError::Checksum { expected, actual } => write!(f, "checksum mismatch: expected {:08x}, found {:08x}", expected, actual),
+            LutError::Malformed(reason) => write!(f, "malformed lookup table: {}", reason),
+        }
+    }
+}
+
+impl Error for LutError {}
+
+impl From<io::Error> for LutError {
+    fn from(err: io::Error) -> LutError {
+        LutError::Io(err)
+    }
+}
+
+impl LookupTable {
+    /// Create the lookup table from the interpolated Virtual (x,y) grids, e.g. `data::X_VIRTUAL_GRID` and `data::Y_VIRTUAL_GRID`
+    pub fn from_grids<R: AsRef<[f64]>>(
+        geometry: &DisplayGeometry,
+        x_virtual_grid: &[R],
+        y_virtual_grid: &[R],
+        layout: MapLayout,
+        method: &str
+    ) -> io::Result<LookupTable> {
+        let maps = PixelMaps::new(geometry, x_virtual_grid, y_virtual_grid, layout);
+        Ok(LookupTable {
+            layout,
+            geometry: *geometry,
+            method: method.to_string(),
+            x_virtual_grid: x_virtual_grid.iter().map(|row| row.as_ref().to_vec()).collect(),
+            y_virtual_grid: y_virtual_grid.iter().map(|row| row.as_ref().to_vec()).collect(),
+            physical_to_virtual: physical_to_virtual_u8(&maps)?,
+            virtual_to_physical: virtual_to_physical_u8(&maps)?,
+        })
+    }
+
+    /// Load and validate the LUT file at `path`
+    pub fn load<P: AsRef<Path>>(path: P) -> Result<LookupTable, LutError> {
+        LookupTable::from_bytes(&fs::read(path)?)
+    }
+
+    /// Save the lookup table to the file at `path`
+    pub fn save<P: AsRef<Path>>(&self, path: P) -> io::Result<()> {
+        let mut out = BufWriter::new(File::create(path)?);
+        self.write(&mut out)?;
+        out.flush()
+    }
+
+    /// Write the lookup table in the LUT file format
+    pub fn write<W: Write + ?Sized>(&self, out: &mut W) -> io::Result<()> {
+        out.write_all(&self.to_bytes()?)
+    }
+
+    /// Encode the lookup table in the LUT file format
+    pub fn to_bytes(&self) -> io::Result<Vec<u8>> {
+        let mut bytes = Vec::new();
+        bytes.extend_from_slice(&MAGIC);
+        bytes.extend_from_slice(&FORMAT_VERSION.to_le_bytes());
+        bytes.push(match self.layout {
+            MapLayout::Quadrant => 0,
+            MapLayout::FullScreen => 1,
+        });
+        for value in geometry_fields(&self.geometry).iter() {
+            bytes.extend_from_slice(&value.to_le_bytes());
+        }
+        push_len(&mut bytes, self.method.len())?;
+        bytes.extend_from_slice(self.method.as_bytes());
+
+        //  Both grids share the same size
+        let (width, height) = table_size(&self.x_virtual_grid)?;
+        if table_size(&self.y_virtual_grid)? != (width, height) {
+            return Err(invalid_data("X and Y grids have different sizes".to_string()));
+        }
+        push_len(&mut bytes, width)?;
+        push_len(&mut bytes, height)?;
+        for value in self.x_virtual_grid.iter().chain(self.y_virtual_grid.iter()).flat_map(|row| row.iter()) {
+            bytes.extend_from_slice(&value.to_le_bytes());
+        }
+
+        let (width, height) = table_size(&self.physical_to_virtual)?;
+        push_len(&mut bytes, width)?;
+        push_len(&mut bytes, height)?;
+        for &(x, y) in self.physical_to_virtual.iter().flat_map(|row| row.iter()) {
+            bytes.extend_from_slice(&[x, y]);
+        }
+
+        let (width, height) = table_size(&self.virtual_to_physical)?;
+        push_len(&mut bytes, width)?;
+        push_len(&mut bytes, height)?;
+        for &(left, top, right, bottom) in self.virtual_to_physical.iter().flat_map(|row| row.iter()) {
+            bytes.extend_from_slice(&[left, top, right, bottom]);
+        }
+
+        let crc = crc32(&bytes);
+        bytes.extend_from_slice(&crc.to_le_bytes());
+        Ok(bytes)
+    }
+
+    /// Decode and validate a lookup table in the LUT file format
+    pub fn from_bytes(bytes: &[u8]) -> Result<LookupTable, LutError> {
+        if bytes.len() < MAGIC.len() || bytes[..MAGIC.len()] != MAGIC {
+            return Err(LutError::Magic);
+        }
+        if bytes.len() < MAGIC.len() + 2 + 4 {
+            return Err(LutError::Malformed("file is truncated".to_string()));
+        }
+        let version = u16::from_le_bytes([bytes[4], bytes[5]]);
+        if version != FORMAT_VERSION {
+            return Err(LutError::Version(version));
+        }
+        let (contents, crc) = bytes.split_at(bytes.len() - 4);
+        let expected = u32::from_le_bytes([crc[0], crc[1], crc[2], crc[3]]);
+        let actual = crc32(contents);
+        if expected != actual {
+            return Err(LutError::Checksum { expected, actual });
+        }
+
+        let mut reader = Reader { bytes: contents, position: MAGIC.len() + 2 };
+        let layout = match reader.u8()? {
+            0 => MapLayout::Quadrant,
+            1 => MapLayout::FullScreen,
+            layout => return Err(LutError::Malformed(format!("unknown layout {}", layout))),
+        };
+        let mut fields = [0.0; 12];
+        for field in fields.iter_mut() {
+            *field = reader.f64()?;
+        }
+        let geometry = geometry_from_fields(&fields);
+        geometry.validate().map_err(|err| LutError::Malformed(err.to_string()))?;
+        let length = reader.u16()? as usize;
+        let method = String::from_utf8(reader.take(length)?.to_vec())
+            .map_err(|_| LutError::Malformed("method name is not UTF-8".to_string()))?;
+
+        let (width, height) = (reader.u16()? as usize, reader.u16()? as usize);
+        let mut grids = [Vec::new(), Vec::new()];
+        for grid in grids.iter_mut() {
+            for _ in 0..height {
+                let row = (0..width).map(|_| reader.f64()).collect::<Result<Vec<_>, _>>()?;
+                grid.push(row);
+            }
+        }
+        let [x_virtual_grid, y_virtual_grid] = grids;
+        if !geometry.fits_grid(&x_virtual_grid) {
+            return Err(LutError::Malformed(format!("grid is {}x{}, which doesn't fit the geometry", width, height)));
+        }
+
+        let (width, height) = (reader.u16()? as usize, reader.u16()? as usize);
+        let mut physical_to_virtual = Vec::new();
+        for _ in 0..height {
+            let row = reader.take(width * 2)?.chunks(2).map(|pixel| (pixel[0], pixel[1])).collect();
+            physical_to_virtual.push(row);
+        }
+
+        let (width, height) = (reader.u16()? as usize, reader.u16()? as usize);
+        let mut virtual_to_physical = Vec::new();
+        for _ in 0..height {
+            let row = reader.take(width * 4)?.chunks(4).map(|bounds| (bounds[0], bounds[1], bounds[2], bounds[3])).collect();
+            virtual_to_physical.push(row);
+        }
+
+        if reader.position != contents.len() {
+            return Err(LutError::Malformed(format!("{} unexpected bytes at the end", contents.len() - reader.position)));
+        }
+        Ok(LookupTable {
+            layout,
+            geometry,
+            method,
+            x_virtual_grid,
+            y_virtual_grid,
+            physical_to_virtual,
+            virtual_to_physical,
+        })
+    }
+}
+
+/// Reads little endian numbers from the contents of a LUT file
+struct Reader<'a> {
+    bytes: &'a [u8],
+    position: usize,
+}
+
+impl<'a> Reader<'a> {
+    fn take(&mut self, count: usize) -> Result<&'a [u8], LutError> {
+        let end = self.position + count;
+        if end > self.bytes.len() {
+            return Err(LutError::Malformed("file is truncated".to_string()));
+        }
+        let result = &self.bytes[self.position..end];
+        self.position = end;
+        Ok(result)
+    }
+
+    fn u8(&mut self) -> Result<u8, LutError> {
+        Ok(self.take(1)?[0])
+    }
+
+    fn u16(&mut self) -> Result<u16, LutError> {
+        let bytes = self.take(2)?;
+        Ok(u16::from_le_bytes([bytes[0], bytes[1]]))
+    }
+
+    fn f64(&mut self) -> Result<f64, LutError> {
+        let mut value = [0; 8];
+        value.copy_from_slice(self.take(8)?);
+        Ok(f64::from_le_bytes(value))
+    }
+}
+
+/// DisplayGeometry fields in declaration order
+fn geometry_fields(geometry: &DisplayGeometry) -> [f64; 12] {
+    [
+        geometry.x_physical_min, geometry.x_physical_max, geometry.y_physical_min, geometry.y_physical_max,
+        geometry.x_virtual_min, geometry.x_virtual_max, geometry.y_virtual_min, geometry.y_virtual_max,
+        geometry.x_physical_increment, geometry.y_physical_increment,
+        geometry.x_virtual_increment, geometry.y_virtual_increment,
+    ]
+}
+
+fn geometry_from_fields(fields: &[f64; 12]) -> DisplayGeometry {
+    DisplayGeometry {
+        x_physical_min: fields[0],
+        x_physical_max: fields[1],
+        y_physical_min: fields[2],
+        y_physical_max: fields[3],
+        x_virtual_min: fields[4],
+        x_virtual_max: fields[5],
+        y_virtual_min: fields[6],
+        y_virtual_max: fields[7],
+        x_physical_increment: fields[8],
+        y_physical_increment: fields[9],
+        x_virtual_increment: fields[10],
+        y_virtual_increment: fields[11],
+    }
+}
+
+/// Return the width and height of a table, failing if the rows differ in length or don't fit in u16
+fn table_size<T>(table: &[Vec<T>]) -> io::Result<(usize, usize)> {
+    let width = table.first().map_or(0, |row| row.len());
+    if table.iter().any(|row| row.len() != width) {
+        return Err(invalid_data("table rows have different lengths".to_string()));
+    }
+    Ok((width, table.len()))
+}
+
+/// Append a length as u16
+fn push_len(bytes: &mut Vec<u8>, len: usize) -> io::Result<()> {
+    if len > u16::MAX as usize {
+        return Err(invalid_data(format!("{} is too large for the lookup table", len)));
+    }
+    bytes.extend_from_slice(&(len as u16).to_le_bytes());
+    Ok(())
+}
+
+fn invalid_data(reason: String) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, reason)
+}
+
+/// CRC-32 (IEEE 802.3), as computed by zlib
+pub fn crc32(bytes: &[u8]) -> u32 {
+    let mut crc = !0u32;
+    for &byte in bytes {
+        crc ^= u32::from(byte);
+        for _ in 0..8 {
+            crc = if crc & 1 != 0 { (crc >> 1) ^ 0xEDB8_8320 } else { crc >> 1 };
+        }
+    }
+    !crc
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::delaunay_creation::{builtin_control_points, triangulation_from_control_points};
+    use crate::interpolation::interpolation_methods::NaturalNeighborInterpolation;
+    use crate::interpolation::{Grid, InterpolationMethod};
+    use crate::VirtualAxis;
+
+    fn lookup_table(layout: MapLayout) -> LookupTable {
+        let geometry = DisplayGeometry::default();
+        let delaunay = triangulation_from_control_points(&builtin_control_points());
+        let grid = Grid::from_delaunay_interpolation(&NaturalNeighborInterpolation, &delaunay, &geometry);
+        LookupTable::from_grids(
+            &geometry, grid.values(VirtualAxis::X), grid.values(VirtualAxis::Y), layout, NaturalNeighborInterpolation.title()
+        ).unwrap()
+    }
+
+    #[test]
+    fn write_read_round_trip() {
+        for &layout in [MapLayout::Quadrant, MapLayout::FullScreen].iter() {
+            let lut = lookup_table(layout);
+            let mut bytes = Vec::new();
+            lut.write(&mut bytes).unwrap();
+            assert_eq!(LookupTable::from_bytes(&bytes).unwrap(), lut, "{:?}", layout);
+        }
+    }
+
+    #[test]
+    fn rejects_flipped_byte() {
+        let bytes = lookup_table(MapLayout::Quadrant).to_bytes().unwrap();
+        for &position in [MAGIC.len() + 2, bytes.len() / 2, bytes.len() - 5].iter() {
+            let mut corrupted = bytes.clone();
+            corrupted[position] ^= 0x01;
+            match LookupTable::from_bytes(&corrupted) {
+                Err(LutError::Checksum { expected, actual }) => assert_ne!(expected, actual),
+                result => panic!("byte {}: expected a checksum error, found {:?}", position, result),
+            }
+        }
+    }
+
+    #[test]
+    fn rejects_other_versions() {
+        let mut bytes = lookup_table(MapLayout::Quadrant).to_bytes().unwrap();
+        bytes[4..6].copy_from_slice(&(FORMAT_VERSION + 1).to_le_bytes());
+        match LookupTable::from_bytes(&bytes) {
+            Err(LutError::Version(version)) => assert_eq!(version, FORMAT_VERSION + 1),
+            result => panic!("expected a version error, found {:?}", result),
+        }
+    }
+
+    #[test]
+    fn crc32_matches_zlib() {
+        assert_eq!(crc32(b"123456789"), 0xCBF4_3926);
+    }
+}
@@ -7,385 +7,942 @@
 // except according to those terms.
 
 //! Interpolate CHIP-8's Emulator Screen (square) to PineTime Display (spherical).
-//! Since the X and Y axes are symmetric, we only compute one quadrant here (X >= 0, Y >= 0)
+//! Command line tool: `generate` the maps, `analyze` the methods, render and export them, or `view` them in 3D.
 /*
- * Press h for help.
- * See ./interpolation.rs for interpolation related code and
- * ./delaunay_creation.rs for loading the data points.
- *
- * *Note*: This demo uses kiss3d which uses an old version of
- * nalgebra. This nalgebra version is incompatible with spade, that's
- * the reason why we're using cgmath points in the delaunay triangulation
- * and nalgebra points for the rendering in kiss3d. Once kiss3d updates,
- * only nalgebra will be used.
+ * Run `nninterpolation help` for the list of commands.
+ * `generate` and `analyze` don't need a graphics stack, so they may be run on a build server.
+ * `view` opens the kiss3d demo and is only available with `--features viewer`.
  */
-#![feature(assoc_int_consts)]  //  Allow f64::MIN and MAX
 #![warn(clippy::all)]
 extern crate cgmath;
+#[macro_use]
+extern crate clap;
+extern crate nninterpolation;
+
+#[cfg(feature = "viewer")]
 extern crate kiss3d;
+#[cfg(feature = "viewer")]
 extern crate nalgebra;
-extern crate noise;
-extern crate rand;
-extern crate spade;
-
-mod constants;
-mod delaunay_creation;
-mod interpolation;
-mod data;
 
-use nalgebra as na;
+#[cfg(feature = "viewer")]
+mod viewer;
 
 use cgmath as cg;
-use cgmath::EuclideanSpace;
-
-use kiss3d::event::{Action, Key, WindowEvent};
-use kiss3d::light::Light;
-use kiss3d::resource::Mesh;
-use kiss3d::scene::SceneNode;
-use kiss3d::window::Window;
-
-use std::cell::RefCell;
-use std::rc::Rc;
-
-use crate::constants::*;
-use crate::delaunay_creation::Delaunay;
-use crate::interpolation::interpolation_methods::{
-    BarycentricInterpolation, FarinC1Interpolation, NaturalNeighborInterpolation,
-    SibsonC1Interpolation,
+use std::fs::File;
+use std::io::{self, BufWriter, Write};
+use clap::{App, AppSettings, Arg, ArgMatches, SubCommand};
+
+use nninterpolation::interpolation::interpolation_methods::{BicubicLatticeInterpolation, NaturalNeighborInterpolation, RadialBasisKernel};
+use nninterpolation::delaunay_creation::Delaunay;
+use nninterpolation::gpu;
+use nninterpolation::parameters::METHOD_NAMES;
+use nninterpolation::spans::{span_report, spans_from_maps, write_c_spans, write_rust_spans};
+use nninterpolation::{
+    get_bounding_box, render_heatmap, write_svg_overlay, triangulation_from_control_points, Artifacts, write_c_header, write_rust_maps, ControlPoint, CoverageMap, DisplayGeometry, Grid,
+    InterpolationMethod, LookupTable, MapLayout, MapSource, Mesh, MeshFormat, MethodParameters, OverdrawReport, PixelMaps, RectangleCover, SphericalProjection, VirtualAxis,
 };
-use crate::interpolation::{Grid, InterpolationMethod};
 
-struct InterpolationRenderData {
-    edges: Vec<(na::Point3<f32>, na::Point3<f32>)>,
-    mesh: Rc<RefCell<Mesh>>,
-    title: &'static str,
+/// Names accepted by `--format`
+const FORMATS: &[&str] = &["text", "rust", "c", "spans", "c-spans", "binary", "json"];
+
+fn main() {
+    let method_arg = Arg::with_name("method")
+        .long("method")
+        .takes_value(true)
+        .possible_values(METHOD_NAMES)
+        .default_value("nn")
+        .help("Interpolation method");
+    let axis_arg = Arg::with_name("axis")
+        .long("axis")
+        .takes_value(true)
+        .possible_values(&["x", "y"])
+        .default_value("y")
+        .help("Virtual Coordinate to render");
+    let matches = App::new("nninterpolation")
+        .about("Interpolate CHIP-8's Emulator Screen to PineTime Display")
+        .version(crate_version!())
+        .setting(AppSettings::SubcommandRequiredElseHelp)
+        .subcommand(
+            SubCommand::with_name("generate")
+                .about("Interpolate the grid and print the Physical / Virtual maps")
+                .args(&common_args())
+                .args(&parameter_args())
+                .arg(method_arg.clone())
+                .arg(
+                    Arg::with_name("full-screen")
+                        .long("full-screen")
+                        .help("Mirror the quadrant into maps for the whole screen, in screen coordinates"),
+                )
+                .arg(
+                    Arg::with_name("format")
+                        .long("format")
+                        .takes_value(true)
+                        .possible_values(FORMATS)
+                        .default_value("text")
+                        .help("Print the grids and maps as text, or write the maps as Rust statics to `include!`, a C header, run-length spans as Rust statics or a C header, a binary lookup table, or everything as JSON"),
+                )
+                .arg(
+                    Arg::with_name("output")
+                        .long("output")
+                        .short("o")
+                        .takes_value(true)
+                        .value_name("FILE")
+                        .help("Write to FILE instead of stdout"),
+                ),
+        )
+        .subcommand(
+            SubCommand::with_name("points")
+                .about("Print the control points as CSV, e.g. to save the points generated by --sphere-radius")
+                .args(&common_args()),
+        )
+        .subcommand(
+            SubCommand::with_name("analyze")
+                .about("Print statistics for the triangulation, grids and maps")
+                .args(&common_args())
+                .args(&parameter_args()),
+        )
+        .subcommand(
+            SubCommand::with_name("render")
+                .about("Render the grid as a PNG heatmap with the control points and triangulation, without OpenGL")
+                .args(&common_args())
+                .args(&parameter_args())
+                .arg(method_arg.clone())
+                .arg(axis_arg.clone())
+                .arg(
+                    Arg::with_name("data")
+                        .long("data")
+                        .help("Render X_VIRTUAL_GRID / Y_VIRTUAL_GRID from data.rs instead of interpolating"),
+                )
+                .arg(
+                    Arg::with_name("scale")
+                        .long("scale")
+                        .takes_value(true)
+                        .value_name("N")
+                        .default_value("4")
+                        .help("Image pixels per grid cell"),
+                )
+                .arg(
+                    Arg::with_name("output")
+                        .long("output")
+                        .short("o")
+                        .takes_value(true)
+                        .value_name("FILE")
+                        .required(true)
+                        .help("PNG file to write"),
+                ),
+        )
+        .subcommand(
+            SubCommand::with_name("overlay")
+                .about("Draw the footprint and Bounding Box of each Virtual Pixel on the Physical display as an SVG")
+                .args(&common_args())
+                .args(&parameter_args())
+                .arg(method_arg.clone())
+                .arg(
+                    Arg::with_name("full-screen")
+                        .long("full-screen")
+                        .help("Draw the whole screen instead of the quadrant"),
+                )
+                .arg(
+                    Arg::with_name("zoom")
+                        .long("zoom")
+                        .takes_value(true)
+                        .value_name("N")
+                        .default_value("8")
+                        .help("SVG units per Physical Pixel"),
+                )
+                .arg(
+                    Arg::with_name("output")
+                        .long("output")
+                        .short("o")
+                        .takes_value(true)
+                        .value_name("FILE")
+                        .required(true)
+                        .help("SVG file to write"),
+                ),
+        )
+        .subcommand(
+            SubCommand::with_name("mesh")
+                .about("Export the interpolated surface, or the triangulation, as an OBJ, PLY or STL mesh")
+                .args(&common_args())
+                .args(&parameter_args())
+                .arg(method_arg.clone())
+                .arg(axis_arg.clone())
+                .arg(
+                    Arg::with_name("triangulation")
+                        .long("triangulation")
+                        .help("Export the triangulation of the control points, with their normals, instead of the surface"),
+                )
+                .arg(
+                    Arg::with_name("output")
+                        .long("output")
+                        .short("o")
+                        .takes_value(true)
+                        .value_name("FILE")
+                        .required(true)
+                        .help("Mesh file to write. The format is chosen by the extension: .obj, .ply or .stl"),
+                ),
+        )
+        .subcommand(
+            SubCommand::with_name("texture")
+                .about("Export the Physical to Virtual map as a 16-bit PNG texture, with GLSL / WGSL shader snippets")
+                .args(&common_args())
+                .args(&parameter_args())
+                .arg(method_arg.clone())
+                .arg(
+                    Arg::with_name("full-screen")
+                        .long("full-screen")
+                        .help("Export the whole screen instead of the quadrant"),
+                )
+                .arg(
+                    Arg::with_name("output")
+                        .long("output")
+                        .short("o")
+                        .takes_value(true)
+                        .value_name("FILE")
+                        .required(true)
+                        .help("PNG texture to write"),
+                )
+                .arg(
+                    Arg::with_name("glsl")
+                        .long("glsl")
+                        .takes_value(true)
+                        .value_name("FILE")
+                        .help("Write the GLSL shader snippet to FILE"),
+                )
+                .arg(
+                    Arg::with_name("wgsl")
+                        .long("wgsl")
+                        .takes_value(true)
+                        .value_name("FILE")
+                        .help("Write the WGSL shader snippet to FILE"),
+                ),
+        )
+        .subcommand(
+            SubCommand::with_name("coverage")
+                .about("Supersample each Physical Pixel and export the anti-aliased weights of the Virtual Pixels covering it")
+                .args(&common_args())
+                .args(&parameter_args())
+                .arg(method_arg.clone())
+                .arg(
+                    Arg::with_name("samples")
+                        .long("samples")
+                        .takes_value(true)
+                        .default_value("4")
+                        .help("Samples per Physical Pixel along each axis"),
+                )
+                .arg(
+                    Arg::with_name("max-virtual")
+                        .long("max-virtual")
+                        .takes_value(true)
+                        .default_value("4")
+                        .help("Most Virtual Pixels blended into a Physical Pixel"),
+                )
+                .arg(
+                    Arg::with_name("format")
+                        .long("format")
+                        .takes_value(true)
+                        .possible_values(&["rust", "json"])
+                        .default_value("rust")
+                        .help("Write the weight table as a Rust static to `include!`, or as JSON"),
+                )
+                .arg(
+                    Arg::with_name("output")
+                        .long("output")
+                        .short("o")
+                        .takes_value(true)
+                        .value_name("FILE")
+                        .help("Write to FILE instead of stdout"),
+                ),
+        )
+        .subcommand(
+            SubCommand::with_name("rectangles")
+                .about("Cover the Physical Pixels of each Virtual Pixel with up to K rectangles, to redraw fewer pixels than the Bounding Boxes")
+                .args(&common_args())
+                .args(&parameter_args())
+                .arg(method_arg.clone())
+                .arg(
+                    Arg::with_name("full-screen")
+                        .long("full-screen")
+                        .help("Cover the whole screen instead of the quadrant"),
+                )
+                .arg(
+                    Arg::with_name("max-rectangles")
+                        .long("max-rectangles")
+                        .takes_value(true)
+                        .value_name("K")
+                        .default_value("4")
+                        .help("Most rectangles per Virtual Pixel"),
+                )
+                .arg(
+                    Arg::with_name("format")
+                        .long("format")
+                        .takes_value(true)
+                        .possible_values(&["report", "rust", "json"])
+                        .default_value("report")
+                        .help("Print the overdraw compared to the Bounding Boxes, or write the rectangles as a Rust static to `include!`, or as JSON"),
+                )
+                .arg(
+                    Arg::with_name("output")
+                        .long("output")
+                        .short("o")
+                        .takes_value(true)
+                        .value_name("FILE")
+                        .help("Write to FILE instead of stdout"),
+                ),
+        )
+        .subcommand(
+            SubCommand::with_name("inspect")
+                .about("Validate a binary lookup table written by `generate --format binary` and print its contents")
+                .arg(Arg::with_name("FILE").required(true).help("Lookup table file")),
+        )
+        .subcommand(
+            SubCommand::with_name("view")
+                .about("Open the kiss3d demo (requires --features viewer)")
+                .args(&common_args())
+                .args(&parameter_args())
+                .arg(axis_arg),
+        )
+        .get_matches();
+
+    match matches.subcommand() {
+        ("generate", Some(args)) => generate(args),
+        ("points", Some(args)) => points(args),
+        ("analyze", Some(args)) => analyze(args),
+        ("render", Some(args)) => render(args),
+        ("overlay", Some(args)) => overlay(args),
+        ("mesh", Some(args)) => mesh(args),
+        ("texture", Some(args)) => texture(args),
+        ("coverage", Some(args)) => coverage(args),
+        ("rectangles", Some(args)) => rectangles(args),
+        ("inspect", Some(args)) => inspect(args),
+        ("view", Some(args)) => view(args),
+        _ => unreachable!(),
+    }
+}
+
+/// Arguments for the control points and display geometry, accepted by all commands
+fn common_args() -> Vec<Arg<'static, 'static>> {
+    vec![
+        Arg::with_name("points")
+            .long("points")
+            .takes_value(true)
+            .value_name("CSV")
+            .help("Load the control points from a CSV file with columns x_physical, y_physical, x_virtual, y_virtual"),
+        Arg::with_name("sphere-radius")
+            .long("sphere-radius")
+            .takes_value(true)
+            .value_name("R")
+            .conflicts_with_all(&["points", "curvature"])
+            .help("Generate the control points by wrapping the Virtual screen onto a sphere of radius R Physical pixels"),
+        Arg::with_name("curvature")
+            .long("curvature")
+            .takes_value(true)
+            .value_name("C")
+            .conflicts_with("points")
+            .help("Generate the control points for a sphere of curvature C = 1 / radius, 0 for a flat screen"),
+        Arg::with_name("lattice")
+            .long("lattice")
+            .takes_value(true)
+            .value_name("WxH")
+            .conflicts_with("points")
+            .help("Number of generated control points along the Virtual X and Y axes [default: 4x4]"),
+        Arg::with_name("geometry")
+            .long("geometry")
+            .takes_value(true)
+            .value_name("FILE")
+            .help("Load the display geometry from a file with `key = value` lines, e.g. `x_physical_max = 120`"),
+        Arg::with_name("physical")
+            .long("physical")
+            .takes_value(true)
+            .value_name("WxH")
+            .help("Physical quadrant size in pixels [default: 120x100]"),
+        Arg::with_name("virtual")
+            .long("virtual")
+            .takes_value(true)
+            .value_name("WxH")
+            .help("Virtual quadrant size in pixels [default: 32x16]"),
+        Arg::with_name("physical-increment")
+            .long("physical-increment")
+            .takes_value(true)
+            .value_name("N")
+            .help("Interpolate the Physical X and Y axes at this increment [default: 1]"),
+        Arg::with_name("virtual-increment")
+            .long("virtual-increment")
+            .takes_value(true)
+            .value_name("N")
+            .help("Compute Bounding Boxes on the Virtual X and Y axes at this increment [default: 1]"),
+    ]
 }
 
-pub fn cg_vec_to_na(vec: cg::Vector3<f64>) -> na::Point3<f32> {
-    na::Point3::new(vec.x as f32, vec.y as f32, vec.z as f32)
+/// Arguments for the parameters of the interpolation methods, accepted by the commands that interpolate
+fn parameter_args() -> Vec<Arg<'static, 'static>> {
+    vec![
+        Arg::with_name("parameters")
+            .long("parameters")
+            .takes_value(true)
+            .value_name("FILE")
+            .help("Load the parameters of the interpolation methods from a file with `key = value` lines, e.g. `smoothness = 2`"),
+        Arg::with_name("smoothness")
+            .long("smoothness")
+            .takes_value(true)
+            .value_name("S")
+            .help("Smoothness factor of sibson [default: 1]"),
+        Arg::with_name("idw-power")
+            .long("idw-power")
+            .takes_value(true)
+            .value_name("P")
+            .help("Power of the distance for idw [default: 2]"),
+        Arg::with_name("idw-neighbours")
+            .long("idw-neighbours")
+            .takes_value(true)
+            .value_name("N")
+            .help("Nearest control points for idw, 0 for all [default: 8]"),
+        Arg::with_name("rbf-kernel")
+            .long("rbf-kernel")
+            .takes_value(true)
+            .possible_values(RadialBasisKernel::NAMES)
+            .help("Radial basis function for tps [default: thin-plate]"),
+        Arg::with_name("rbf-shape")
+            .long("rbf-shape")
+            .takes_value(true)
+            .value_name("E")
+            .help("Shape parameter of the gaussian and multiquadric kernels, in 1 / Physical pixels [default: 0.02]"),
+        Arg::with_name("rbf-regularisation")
+            .long("rbf-regularisation")
+            .takes_value(true)
+            .value_name("L")
+            .help("Regularisation of tps, 0 to pass exactly through the control points [default: 0]"),
+        Arg::with_name("bicubic-lattice")
+            .long("bicubic-lattice")
+            .takes_value(true)
+            .value_name("WxH")
+            .help("Columns and rows of the control point lattice for bicubic [default: detected from the control points]"),
+    ]
 }
 
-impl InterpolationRenderData {
-    fn new<I: InterpolationMethod>(delaunay: &Delaunay) -> InterpolationRenderData {
-        println!("{}", I::title());
-        let grid = Grid::<I>::from_delaunay_interpolation(delaunay);
-        let (vertices, indices) = grid.get_triangles();
-        let mesh = Mesh::new(vertices, indices, None, None, false);
-        InterpolationRenderData {
-            edges: grid.get_edges(),
-            mesh: Rc::new(RefCell::new(mesh)),
-            title: I::title(),
+/// Interpolate the X and Y grids with the selected method, then print the grids and the maps for the CHIP-8 Emulator
+fn generate(args: &ArgMatches) {
+    let geometry = load_geometry(args);
+    let delaunay = load_triangulation(args, &geometry);
+    let parameters = load_parameters(args);
+    let method = load_method(args, &parameters, &delaunay);
+    let layout = if args.is_present("full-screen") { MapLayout::FullScreen } else { MapLayout::Quadrant };
+    let grid = Grid::from_delaunay_interpolation(&*method, &delaunay, &geometry);
+    let maps = PixelMaps::new(&geometry, grid.values(VirtualAxis::X), grid.values(VirtualAxis::Y), layout);
+    if args.value_of("format") == Some("text") {
+        if args.is_present("output") {
+            eprintln!("--output needs a --format other than text");
+            std::process::exit(1);
         }
+        grid.print(VirtualAxis::X);
+        grid.print(VirtualAxis::Y);
+
+        //  Generate the map of Physical Pixels to Virtual Pixels. Used by the CHIP-8 Emulator to decide which Virtual Pixel to fetch the colour value when rendering a Physical Pixel.
+        maps.print_physical_to_virtual();
+
+        //  Generate the map of Virtual Pixels to Physical Pixels. Used by the CHIP-8 Emulator to decide which Physical Pixels to redraw when a Virtual Pixel is updated.
+        maps.print_virtual_to_physical();
+        return;
+    }
+
+    if args.value_of("format") == Some("binary") {
+        let path = args.value_of("output").unwrap_or_else(|| {
+            eprintln!("--format binary needs --output");
+            std::process::exit(1);
+        });
+        let result = LookupTable::from_grids(&geometry, grid.values(VirtualAxis::X), grid.values(VirtualAxis::Y), layout, method.title())
+            .and_then(|lut| lut.save(path));
+        if let Err(err) = result {
+            eprintln!("{}: {}", path, err);
+            std::process::exit(1);
+        }
+        return;
     }
-}
 
-fn print_help() {
-    println!("Interpolation Demo");
-    println!("H - print this help");
-    println!("N - show / hide normals");
-    println!("G - switch interpolation method");
-    println!("D - show / hide delaunay triangulation");
-    println!("T - toggle display method of interpolated mesh");
+    let control_points = control_points_source(args);
+    let source = MapSource { method: method.title(), control_points: &control_points };
+    let write = |out: &mut dyn Write| match args.value_of("format") {
+        Some("c") => write_c_header(out, &maps, &source),
+        Some("spans") => write_rust_spans(out, &maps, &source),
+        Some("c-spans") => write_c_spans(out, &maps, &source),
+        Some("json") => Artifacts::new(&*method, &load_all_methods(&parameters, &delaunay), &delaunay, &geometry, layout, &source).write(out),
+        _ => write_rust_maps(out, &maps, &source),
+    };
+    let result = write_output(args.value_of("output"), write);
+    if let Err(err) = result {
+        eprintln!("{}: {}", args.value_of("output").unwrap_or("stdout"), err);
+        std::process::exit(1);
+    }
 }
 
-#[derive(PartialEq, Eq)]
-enum DelaunayVisibility {
-    All,
-    OnlyLines,
-    None,
+/// Create the triangulation from the control points
+fn load_triangulation(args: &ArgMatches, geometry: &DisplayGeometry) -> Delaunay {
+    triangulation_from_control_points(&load_control_points(args, geometry))
 }
 
-#[derive(PartialEq, Eq)]
-enum GridRenderType {
-    Lines,
-    Polygons,
+/// Load the control points from the `--points` CSV file, generate them with the spherical projection,
+/// or return the built-in control points
+fn load_control_points(args: &ArgMatches, geometry: &DisplayGeometry) -> Vec<ControlPoint> {
+    if let Some(path) = args.value_of("points") {
+        return nninterpolation::load_control_points(path).unwrap_or_else(|err| {
+            eprintln!("{}: {}", path, err);
+            std::process::exit(1);
+        });
+    }
+    if !(args.is_present("sphere-radius") || args.is_present("curvature") || args.is_present("lattice")) {
+        return nninterpolation::builtin_control_points();
+    }
+    let mut projection = SphericalProjection::default();
+    if let Some(radius) = number_arg(args, "sphere-radius") {
+        projection.curvature = 1.0 / radius;
+    }
+    if let Some(curvature) = number_arg(args, "curvature") {
+        projection.curvature = curvature;
+    }
+    if let Some((x_lattice, y_lattice)) = size_arg(args, "lattice") {
+        projection.x_lattice = x_lattice as usize;
+        projection.y_lattice = y_lattice as usize;
+    }
+    projection.control_points(geometry).unwrap_or_else(|err| {
+        eprintln!("{}", err);
+        std::process::exit(1);
+    })
 }
 
-impl DelaunayVisibility {
-    fn next(&self) -> DelaunayVisibility {
-        use crate::DelaunayVisibility::*;
-        match self {
-            All => OnlyLines,
-            OnlyLines => None,
-            None => All,
+/// Describe where the control points came from, for the header of the generated files
+fn control_points_source(args: &ArgMatches) -> String {
+    if let Some(path) = args.value_of("points") {
+        return path.to_string();
+    }
+    if !(args.is_present("sphere-radius") || args.is_present("curvature") || args.is_present("lattice")) {
+        return "built-in".to_string();
+    }
+    let mut options = Vec::new();
+    for name in &["sphere-radius", "curvature", "lattice"] {
+        if let Some(value) = args.value_of(name) {
+            options.push(format!("--{} {}", name, value));
         }
     }
+    format!("spherical projection, {}", options.join(" "))
 }
 
-impl GridRenderType {
-    fn next(&self) -> GridRenderType {
-        use crate::GridRenderType::*;
-        match self {
-            Lines => Polygons,
-            Polygons => Lines,
-        }
+/// Create the display geometry from the `--geometry` file, overridden by the size and increment arguments
+fn load_geometry(args: &ArgMatches) -> DisplayGeometry {
+    let mut geometry = match args.value_of("geometry") {
+        Some(path) => DisplayGeometry::load(path).unwrap_or_else(|err| {
+            eprintln!("{}: {}", path, err);
+            std::process::exit(1);
+        }),
+        None => DisplayGeometry::default(),
+    };
+    if let Some((width, height)) = size_arg(args, "physical") {
+        geometry.x_physical_max = geometry.x_physical_min + width;
+        geometry.y_physical_max = geometry.y_physical_min + height;
+    }
+    if let Some((width, height)) = size_arg(args, "virtual") {
+        geometry.x_virtual_max = geometry.x_virtual_min + width;
+        geometry.y_virtual_max = geometry.y_virtual_min + height;
+    }
+    if let Some(increment) = number_arg(args, "physical-increment") {
+        geometry.x_physical_increment = increment;
+        geometry.y_physical_increment = increment;
     }
+    if let Some(increment) = number_arg(args, "virtual-increment") {
+        geometry.x_virtual_increment = increment;
+        geometry.y_virtual_increment = increment;
+    }
+    if let Err(err) = geometry.validate() {
+        eprintln!("{}", err);
+        std::process::exit(1);
+    }
+    geometry
 }
 
-fn main() {
-    let mut window = Window::new("Delaunay Demo");
-    window.set_light(Light::StickToCamera);
-
-    print_help();
-
-    let mut delaunay_visibility = DelaunayVisibility::All;
-    let mut grid_render_type = GridRenderType::Lines;
-    let mut show_normals = false;
-
-    // Create delaunay triangulation and its mesh
-    let delaunay = crate::delaunay_creation::generate_random_triangulation();
-    let delaunay_mesh = create_mesh_from_triangulation(&delaunay);
-    let delaunay_mesh = Rc::new(RefCell::new(delaunay_mesh));
-    let mut delaunay_node = window.add_mesh(delaunay_mesh.clone(), na::Vector3::new(1.0, 1.0, 1.0));
-    delaunay_node.enable_backface_culling(false);
-    let delaunay_lines = extract_edges(&delaunay);
-
-    let interpolation_meshes = [
-        InterpolationRenderData::new::<BarycentricInterpolation>(&delaunay),
-        InterpolationRenderData::new::<NaturalNeighborInterpolation>(&delaunay),
-        InterpolationRenderData::new::<SibsonC1Interpolation>(&delaunay),
-        InterpolationRenderData::new::<FarinC1Interpolation>(&delaunay),
+/// Create the parameters of the interpolation methods from the `--parameters` file, overridden by the
+/// parameter arguments, e.g. `--smoothness`
+fn load_parameters(args: &ArgMatches) -> MethodParameters {
+    let mut parameters = match args.value_of("parameters") {
+        Some(path) => MethodParameters::load(path).unwrap_or_else(|err| {
+            eprintln!("{}: {}", path, err);
+            std::process::exit(1);
+        }),
+        None => MethodParameters::default(),
+    };
+    let keys = [
+        ("smoothness", "smoothness"),
+        ("idw-power", "idw_power"),
+        ("idw-neighbours", "idw_neighbours"),
+        ("rbf-kernel", "rbf_kernel"),
+        ("rbf-shape", "rbf_shape"),
+        ("rbf-regularisation", "rbf_regularisation"),
+        ("bicubic-lattice", "bicubic_lattice"),
     ];
-
-    let mut cur_interpolation_mesh_node: Option<SceneNode> = None;
-    let mut cur_interpolation_mesh_index = 0;
-
-    let normals = get_normals(&delaunay);
-
-    //  Generate the map of Physical Pixels to Virtual Pixels. Used by the CHIP-8 Emulator to decide which Physical Pixels to redraw when a Virtual Pixel is updated.
-    generate_physical_to_virtual_map();
-
-    //  Generate the map of Virtual Pixels to Physical Pixels. Used by the CHIP-8 Emulator to decide which Physical Pixels to redraw when a Virtual Pixel is updated.
-    generate_virtual_to_physical_map();
-
-    while window.render() {
-        for event in window.events().iter() {
-            let mut update_interpolation_mesh = false;
-            match event.value {
-                WindowEvent::Key(Key::H, Action::Press, _) => print_help(),
-                WindowEvent::Key(Key::N, Action::Press, _) => show_normals = !show_normals,
-                WindowEvent::Key(Key::G, Action::Press, _) => {
-                    cur_interpolation_mesh_index += 1;
-                    update_interpolation_mesh = true;
-                    if cur_interpolation_mesh_index > interpolation_meshes.len() {
-                        cur_interpolation_mesh_index = 0;
-                    }
-                    if cur_interpolation_mesh_index < interpolation_meshes.len() {
-                        println!(
-                            "Change interpolation method to {}",
-                            interpolation_meshes[cur_interpolation_mesh_index].title
-                        );
-                    }
-                }
-                WindowEvent::Key(Key::T, Action::Press, _) => {
-                    grid_render_type = grid_render_type.next();
-                    update_interpolation_mesh = true;
-                }
-                WindowEvent::Key(Key::D, Action::Press, _) => {
-                    delaunay_visibility = delaunay_visibility.next();
-                    if delaunay_visibility == DelaunayVisibility::All {
-                        delaunay_node = window
-                            .scene_mut()
-                            .add_mesh(delaunay_mesh.clone(), na::Vector3::new(1.0, 1.0, 1.));
-                        delaunay_node.enable_backface_culling(false);
-                    } else {
-                        delaunay_node.unlink();
-                    }
-                }
-                _ => {}
-            }
-            if update_interpolation_mesh {
-                if let Some(mut mesh_node) = cur_interpolation_mesh_node {
-                    mesh_node.unlink();
-                    cur_interpolation_mesh_node = None;
-                }
-                if cur_interpolation_mesh_index < interpolation_meshes.len()
-                    && grid_render_type == GridRenderType::Polygons
-                {
-                    let mut new_node = window.scene_mut().add_mesh(
-                        interpolation_meshes[cur_interpolation_mesh_index]
-                            .mesh
-                            .clone(),
-                        na::Vector3::new(1.0, 1.0, 1.0),
-                    );
-                    new_node.enable_backface_culling(false);
-                    cur_interpolation_mesh_node = Some(new_node);
-                }
+    for &(name, key) in keys.iter() {
+        if let Some(value) = args.value_of(name) {
+            if let Err(err) = parameters.set(key, value) {
+                eprintln!("--{}: {}", name, err);
+                std::process::exit(1);
             }
         }
+    }
+    if let Err(err) = parameters.validate() {
+        eprintln!("{}", err);
+        std::process::exit(1);
+    }
+    parameters
+}
+
+/// Create the interpolation method for `--method` with the parameters, fitted to the control points
+fn load_method(args: &ArgMatches, parameters: &MethodParameters, delaunay: &Delaunay) -> Box<dyn InterpolationMethod> {
+    let method = parameters.method(args.value_of("method").unwrap(), delaunay).unwrap_or_else(|err| {
+        eprintln!("{}", err);
+        std::process::exit(1);
+    });
+    warn_fallback(&*method);
+    method
+}
 
-        if delaunay_visibility == DelaunayVisibility::All
-            || delaunay_visibility == DelaunayVisibility::OnlyLines
-        {
-            let color = na::Point3::new(0.8, 0.5, 0.2);
-            for &(from, to) in &delaunay_lines {
-                window.draw_line(&from, &to, &color);
+/// Create every interpolation method with the parameters, fitted to the control points.
+/// A method that can't be created, e.g. a spline that can't be fitted, is skipped with a warning.
+fn load_all_methods(parameters: &MethodParameters, delaunay: &Delaunay) -> Vec<Box<dyn InterpolationMethod>> {
+    let mut methods = Vec::new();
+    for name in METHOD_NAMES.iter() {
+        match parameters.method(name, delaunay) {
+            Ok(method) => {
+                warn_fallback(&*method);
+                methods.push(method);
             }
+            Err(err) => eprintln!("warning: skipping {}: {}", name, err),
         }
+    }
+    if methods.is_empty() {
+        eprintln!("none of the interpolation methods could be created");
+        std::process::exit(1);
+    }
+    methods
+}
 
-        if grid_render_type == GridRenderType::Lines {
-            if let Some(mesh) = interpolation_meshes.get(cur_interpolation_mesh_index) {
-                let color = na::Point3::new(0.5, 0.8, 0.2);
-                for &(from, to) in &mesh.edges {
-                    window.draw_line(&from, &to, &color);
-                }
-            }
+/// Warn if bicubic fell back to natural neighbor interpolation because the control points don't form a lattice
+fn warn_fallback(method: &dyn InterpolationMethod) {
+    if method.title() == BicubicLatticeInterpolation::FALLBACK_TITLE {
+        eprintln!("warning: the control points don't form a lattice, bicubic falls back to natural neighbor interpolation");
+    }
+}
+
+/// Parse a `WxH` argument
+fn size_arg(args: &ArgMatches, name: &str) -> Option<(f64, f64)> {
+    let value = args.value_of(name)?;
+    let mut parts = value.splitn(2, 'x');
+    match (parts.next().map(str::parse), parts.next().map(str::parse)) {
+        (Some(Ok(width)), Some(Ok(height))) => Some((width, height)),
+        _ => {
+            eprintln!("--{} expects WxH, e.g. 120x100, found `{}`", name, value);
+            std::process::exit(1);
         }
+    }
+}
 
-        if show_normals {
-            let color = na::Point3::new(0.5, 0.5, 1.0);
-            for &(from, to) in &normals {
-                window.draw_line(&from, &to, &color);
-            }
+/// Parse a numeric argument
+fn number_arg(args: &ArgMatches, name: &str) -> Option<f64> {
+    let value = args.value_of(name)?;
+    match value.parse() {
+        Ok(number) => Some(number),
+        Err(_) => {
+            eprintln!("--{} expects a number, found `{}`", name, value);
+            std::process::exit(1);
         }
     }
 }
 
-/// For all Physical (x,y) Coordinates, return the corresponding Virtual (x,y) Coordinates.
-/// Used by the CHIP-8 Emulator to decide which Virtual Pixel to fetch the colour value when rendering a Physical Pixel.
-fn generate_physical_to_virtual_map() {
-    println!("PHYSICAL_TO_VIRTUAL_MAP=");
-    print!("[");
-    for y in 0..Y_PHYSICAL_SUBDIVISIONS {
-        print!("[");
-        for x in 0..X_PHYSICAL_SUBDIVISIONS {
-            //  Convert the normalised (x,y) into Physical (x,y) Coordinates
-            //  let physical_point = transform_physical_point(cg::Point2::new(x as f64, y as f64));
-            //  Construct the interpolated Virtual (x,y) Coordinates
-            let virtual_point = cg::Point2::new(
-                data::X_VIRTUAL_GRID[y][x] as f64, 
-                data::Y_VIRTUAL_GRID[y][x] as f64
-            );
-            print!("({:.0},{:.0}),", virtual_point.x, virtual_point.y);
+/// Print the control points in the CSV format accepted by `--points`
+fn points(args: &ArgMatches) {
+    let geometry = load_geometry(args);
+    let points = load_control_points(args, &geometry);
+    println!("x_physical,y_physical,x_virtual,y_virtual");
+    for point in points {
+        println!("{},{},{},{}", point.x_physical, point.y_physical, point.x_virtual, point.y_virtual);
+    }
+}
+
+/// Print statistics for the triangulation, each interpolation method and the maps
+fn analyze(args: &ArgMatches) {
+    let geometry = load_geometry(args);
+    let delaunay = load_triangulation(args, &geometry);
+    println!("Control points: {}", delaunay.num_vertices());
+    println!("Triangles: {}", delaunay.num_triangles());
+
+    println!("X_VIRTUAL_GRID / Y_VIRTUAL_GRID range per interpolation method:");
+    for method in load_all_methods(&load_parameters(args), &delaunay) {
+        analyze_grids(&*method, &delaunay, &geometry);
+    }
+
+    //  Count the Virtual Pixels that aren't rendered by any Physical Pixel
+    let grid = Grid::from_delaunay_interpolation(&NaturalNeighborInterpolation, &delaunay, &geometry);
+    let mut missing = Vec::new();
+    for y in 0..geometry.y_virtual_subdivisions() {
+        for x in 0..geometry.x_virtual_subdivisions() {
+            let pos = geometry.transform_virtual_point(cg::Point2::new(x as f64, y as f64));
+            if get_bounding_box(&geometry, grid.values(VirtualAxis::X), grid.values(VirtualAxis::Y), pos.x, pos.y).is_none() {
+                missing.push((pos.x, pos.y));
+            }
         }
-        println!("],");
-    }
-    println!("]\n");    
-}
-
-/// For all Virtual (x,y) Coordinates, compute the Bounding Box that encloses the corresponding Physical (x,y) Coordinates.
-/// Used by the CHIP-8 Emulator to decide which Physical Pixels to redraw when a Virtual Pixel is updated.
-fn generate_virtual_to_physical_map() {
-    println!("VIRTUAL_TO_PHYSICAL_MAP=");
-    print!("[");
-    for y in 0..Y_VIRTUAL_SUBDIVISIONS {
-        print!("[");
-        for x in 0..X_VIRTUAL_SUBDIVISIONS {
-            //  Convert the normalised (x,y) into Virtual (x,y) Coordinates
-            let pos = transform_virtual_point(cg::Point2::new(x as f64, y as f64));
-            //  For all Physical (x,y) that interpolate to the Virtual (x,y), find the bounding box
-            let bounding_box = get_bounding_box(
-                data::X_VIRTUAL_GRID,
-                data::Y_VIRTUAL_GRID,
-                pos.x,
-                pos.y);  //  Returns (left, top, right, bottom) for the Bounding Box
-            if let Some((left, top, right, bottom)) = bounding_box {
-                print!("({:.0},{:.0},{:.0},{:.0}),", left, top, right, bottom);
-                /* if left as u8 == right as u8 && top as u8 == bottom as u8 {
-                    print!("****");  //  Flag out Virtual Points that map to a single Physical Point
-                } */
-            } else {
-                print!("(255,255,255,255),");
+    }
+    println!(
+        "Virtual pixels without Physical pixels ({}): {} of {}",
+        grid.title(),
+        missing.len(),
+        geometry.x_virtual_subdivisions() * geometry.y_virtual_subdivisions()
+    );
+    for (x, y) in missing {
+        println!("  ({:.0},{:.0})", x, y);
+    }
+
+    //  Compare the size of PHYSICAL_TO_VIRTUAL_MAP before and after run-length compression
+    println!("PHYSICAL_TO_VIRTUAL_MAP compressed into spans ({}):", grid.title());
+    for &layout in [MapLayout::Quadrant, MapLayout::FullScreen].iter() {
+        let maps = PixelMaps::new(&geometry, grid.values(VirtualAxis::X), grid.values(VirtualAxis::Y), layout);
+        match spans_from_maps(&maps) {
+            Ok(spans) => {
+                let report = span_report(&spans);
+                println!(
+                    "  {:?}: {} pixels in {} spans, {} bytes instead of {} bytes ({:.0}%)",
+                    layout, report.physical_pixels, report.spans, report.compressed_bytes,
+                    report.uncompressed_bytes, report.ratio() * 100.0
+                );
             }
-            //  println!("XVirtual={:.0}, YVirtual={:.0}, BoundBox={:.?}", pos.x, pos.y, bounding_box);
+            Err(err) => println!("  {:?}: {}", layout, err),
         }
-        println!("],");
-    }
-    println!("]\n");    
-}
-
-/// Given a grid of Physical (x,y) Coordinates and their interpolated Virtual (x,y) Coordinates, 
-/// find all Physical (x,y) Coordinates that interpolate to (x_virtual,y_virtual).
-/// Return the (left, top, right, bottom) of the Bounding Box that encloses these found points.
-/// x_virtual and y_virtual are truncated to integer during comparison.
-/// Function returns `None` if (x_virtual,y_virtual) was not found.
-fn get_bounding_box(
-    x_virtual_grid: &[[f64; X_PHYSICAL_SUBDIVISIONS + 1]; Y_PHYSICAL_SUBDIVISIONS + 1],
-    y_virtual_grid: &[[f64; X_PHYSICAL_SUBDIVISIONS + 1]; Y_PHYSICAL_SUBDIVISIONS + 1],
-    x_virtual: f64,
-    y_virtual: f64
-) -> Option<(f64, f64, f64, f64)> {
-    let mut left: f64 = f64::MAX;
-    let mut top: f64 = f64::MAX;
-    let mut right: f64 = f64::MIN;
-    let mut bottom: f64 = f64::MIN;
-    //  For all Physical (x,y) Coordinates...
-    for y in 0..=Y_PHYSICAL_SUBDIVISIONS {
-        for x in 0..=X_PHYSICAL_SUBDIVISIONS {
-            //  Get the Physical (x,y) Coordinates
-            let pos = transform_physical_point(cg::Point2::new(x as f64, y as f64));
-
-            //  Get the interpolated Virtual (x,y) Coordinates
-            let x_interpolated = x_virtual_grid[y][x].floor();
-            let y_interpolated = y_virtual_grid[y][x].floor();
-
-            //  Skip if not matching
-            if x_interpolated as u8 != x_virtual as u8 || 
-                y_interpolated as u8 != y_virtual as u8 { continue; }
-
-            //  Find the Bounding Box of the Physical (x,y) Coordinates
-            if pos.x < left   { left   = pos.x; }
-            if pos.y < top    { top    = pos.y; }
-            if pos.x > right  { right  = pos.x; }
-            if pos.y > bottom { bottom = pos.y; }
+    }
+}
+
+fn analyze_grids(method: &dyn InterpolationMethod, delaunay: &Delaunay, geometry: &DisplayGeometry) {
+    let grid = Grid::from_delaunay_interpolation(method, delaunay, geometry);
+    let (x_min, x_max) = grid_range(grid.values(VirtualAxis::X));
+    let (y_min, y_max) = grid_range(grid.values(VirtualAxis::Y));
+    println!("  {}: {:.1} to {:.1} / {:.1} to {:.1}", grid.title(), x_min, x_max, y_min, y_max);
+}
+
+/// Return the min and max values of the grid
+fn grid_range(grid: &[Vec<f64>]) -> (f64, f64) {
+    let mut min = f64::MAX;
+    let mut max = f64::MIN;
+    for &val in grid.iter().flat_map(|row| row.iter()) {
+        min = min.min(val);
+        max = max.max(val);
+    }
+    (min, max)
+}
+
+/// Render the grid for `--axis` as a PNG heatmap
+fn render(args: &ArgMatches) {
+    let geometry = load_geometry(args);
+    let delaunay = load_triangulation(args, &geometry);
+    let axis = axis_arg(args);
+    let scale = number_arg(args, "scale").unwrap() as usize;
+    if scale == 0 {
+        eprintln!("--scale must be at least 1");
+        std::process::exit(1);
+    }
+    let image = if args.is_present("data") {
+        let grid = match axis {
+            VirtualAxis::X => nninterpolation::data::X_VIRTUAL_GRID,
+            VirtualAxis::Y => nninterpolation::data::Y_VIRTUAL_GRID,
+        };
+        if !geometry.fits_grid(grid) {
+            eprintln!("{} doesn't fit the geometry, rebuild with the same geometry", axis.grid_name());
+            std::process::exit(1);
         }
+        render_heatmap(&geometry, grid, axis, Some(&delaunay), scale)
+    } else {
+        let method = load_method(args, &load_parameters(args), &delaunay);
+        let grid = Grid::from_delaunay_interpolation(&*method, &delaunay, &geometry);
+        render_heatmap(&geometry, grid.values(axis), axis, Some(&delaunay), scale)
     };
-    if left < f64::MAX && top < f64::MAX &&
-        right > f64::MIN && bottom > f64::MIN {  //  (x_virtual,y_virtual) found
-            Some((left.floor(), top.floor(), right.floor(), bottom.floor())) 
-    } else { None }  //  (x_virtual,y_virtual) not found
-}
-    
-/// Given a normalised point, return the Physical (x,y) Coordinates
-fn transform_physical_point(v: cg::Point2<f64>) -> cg::Point2<f64> {
-    cg::Point2::new(
-        v.x * X_PHYSICAL_SCALE - PHYSICAL_OFFSET.x,
-        v.y * Y_PHYSICAL_SCALE - PHYSICAL_OFFSET.y
-    )
-    //  Previously: cg::Point2::from_vec((v * SCALE).to_vec() - GRID_OFFSET)
+    let path = args.value_of("output").unwrap();
+    if let Err(err) = image.save_png(path) {
+        eprintln!("{}: {}", path, err);
+        std::process::exit(1);
+    }
 }
 
-/// Given a normalised point, return the Virtual (x,y) Coordinates
-fn transform_virtual_point(v: cg::Point2<f64>) -> cg::Point2<f64> {
-    cg::Point2::new(
-        v.x * X_VIRTUAL_SCALE - VIRTUAL_OFFSET.x,
-        v.y * Y_VIRTUAL_SCALE - VIRTUAL_OFFSET.y
-    )
+/// Write the SVG overlay of the Virtual Pixel footprints
+fn overlay(args: &ArgMatches) {
+    let maps = load_maps(args);
+    let zoom = number_arg(args, "zoom").unwrap();
+    let path = args.value_of("output").unwrap();
+    let result = write_file(path, |out| write_svg_overlay(out, &maps, zoom));
+    if let Err(err) = result {
+        eprintln!("{}: {}", path, err);
+        std::process::exit(1);
+    }
+}
+
+/// Export the texture and shader snippets for the desktop preview
+fn texture(args: &ArgMatches) {
+    let maps = load_maps(args);
+    let path = args.value_of("output").unwrap();
+    let mut result = write_file(path, |out| gpu::write_texture_png(out, &maps));
+    if result.is_ok() {
+        if let Some(glsl) = args.value_of("glsl") {
+            result = write_file(glsl, |out| gpu::write_glsl(out, &maps));
+        }
+    }
+    if result.is_ok() {
+        if let Some(wgsl) = args.value_of("wgsl") {
+            result = write_file(wgsl, |out| gpu::write_wgsl(out, &maps));
+        }
+    }
+    if let Err(err) = result {
+        eprintln!("{}", err);
+        std::process::exit(1);
+    }
+}
+
+/// Interpolate the grids with `--method` and generate the maps for `--full-screen`
+fn load_maps(args: &ArgMatches) -> PixelMaps {
+    let geometry = load_geometry(args);
+    let delaunay = load_triangulation(args, &geometry);
+    let method = load_method(args, &load_parameters(args), &delaunay);
+    let layout = if args.is_present("full-screen") { MapLayout::FullScreen } else { MapLayout::Quadrant };
+    let grid = Grid::from_delaunay_interpolation(&*method, &delaunay, &geometry);
+    PixelMaps::new(&geometry, grid.values(VirtualAxis::X), grid.values(VirtualAxis::Y), layout)
 }
 
-fn get_normals(delaunay: &Delaunay) -> Vec<(na::Point3<f32>, na::Point3<f32>)> {
-    let mut result = Vec::new();
-    for v in delaunay.vertices() {
-        let n = v.normal;
-        let p = v.position_3d();
-        result.push((cg_vec_to_na(p.to_vec()), cg_vec_to_na(p.to_vec() - n * 0.3)));
+/// Export the surface or the triangulation as a mesh, with the Virtual Coordinate on `--axis` as the height
+fn mesh(args: &ArgMatches) {
+    let path = args.value_of("output").unwrap();
+    let format = MeshFormat::from_path(path).unwrap_or_else(|| {
+        eprintln!("{}: expected the extension .obj, .ply or .stl", path);
+        std::process::exit(1);
+    });
+    let geometry = load_geometry(args);
+    let delaunay = load_triangulation(args, &geometry);
+    let axis = axis_arg(args);
+    let mesh = if args.is_present("triangulation") {
+        Mesh::from_triangulation(&delaunay, axis)
+    } else {
+        let method = load_method(args, &load_parameters(args), &delaunay);
+        Mesh::from_grid(&Grid::from_delaunay_interpolation(&*method, &delaunay, &geometry), axis)
+    };
+    let result = write_file(path, |out| mesh.write(out, format));
+    if let Err(err) = result {
+        eprintln!("{}: {}", path, err);
+        std::process::exit(1);
     }
-    result
 }
 
-fn extract_edges(delaunay: &Delaunay) -> Vec<(na::Point3<f32>, na::Point3<f32>)> {
-    let offset = cg::Vector3::new(0., 0., -0.01);
-    let mut lines = Vec::new();
-    for edge in delaunay.edges() {
-        let from_pos = cg_vec_to_na(edge.from().position_3d().to_vec() + offset);
-        let to_pos = cg_vec_to_na(edge.to().position_3d().to_vec() + offset);
-        lines.push((from_pos, to_pos));
+/// Export the anti-aliased coverage map for `--samples` and `--max-virtual`
+fn coverage(args: &ArgMatches) {
+    let samples = number_arg(args, "samples").unwrap() as usize;
+    let max_virtual = number_arg(args, "max-virtual").unwrap() as usize;
+    if samples == 0 || max_virtual == 0 {
+        eprintln!("--samples and --max-virtual must be at least 1");
+        std::process::exit(1);
+    }
+    let geometry = load_geometry(args);
+    let delaunay = load_triangulation(args, &geometry);
+    let method = load_method(args, &load_parameters(args), &delaunay);
+    let coverage = CoverageMap::new(&*method, &delaunay, &geometry, samples, max_virtual);
+    let write = |out: &mut dyn Write| match args.value_of("format") {
+        Some("json") => coverage.write_json(out),
+        _ => coverage.write_rust(out),
+    };
+    let result = write_output(args.value_of("output"), write);
+    if let Err(err) = result {
+        eprintln!("{}: {}", args.value_of("output").unwrap_or("stdout"), err);
+        std::process::exit(1);
     }
-    lines
 }
 
-fn create_mesh_from_triangulation(delaunay: &Delaunay) -> Mesh {
-    let mut coords = Vec::new();
-    let mut faces = Vec::new();
-    for vertex in delaunay.vertices() {
-        coords.push(cg_vec_to_na(vertex.position_3d().to_vec()));
+/// Cover each Virtual Pixel with up to `--max-rectangles` rectangles and print or write them
+fn rectangles(args: &ArgMatches) {
+    let max_rectangles = number_arg(args, "max-rectangles").unwrap() as usize;
+    if max_rectangles == 0 {
+        eprintln!("--max-rectangles must be at least 1");
+        std::process::exit(1);
     }
-    for triangle in delaunay.triangles() {
-        let triangle = triangle.as_triangle();
-        let h0 = triangle[0].fix();
-        let h1 = triangle[1].fix();
-        let h2 = triangle[2].fix();
-        faces.push(na::Point3::new(h0 as u16, h1 as u16, h2 as u16));
+    let cover = RectangleCover::new(&load_maps(args), max_rectangles);
+    let write = |out: &mut dyn Write| match args.value_of("format") {
+        Some("rust") => cover.write_rust(out),
+        Some("json") => cover.write_json(out),
+        _ => write_overdraw_report(out, &cover.report),
+    };
+    let result = write_output(args.value_of("output"), write);
+    if let Err(err) = result {
+        eprintln!("{}: {}", args.value_of("output").unwrap_or("stdout"), err);
+        std::process::exit(1);
+    }
+}
+
+/// Write to the file at `path` and flush it, so that an error on the last buffered write is reported
+fn write_file<F: FnOnce(&mut dyn Write) -> io::Result<()>>(path: &str, write: F) -> io::Result<()> {
+    let mut out = BufWriter::new(File::create(path)?);
+    write(&mut out)?;
+    out.flush()
+}
+
+/// Write to the file at `path` if given, otherwise to stdout, and flush it
+fn write_output<F: FnOnce(&mut dyn Write) -> io::Result<()>>(path: Option<&str>, write: F) -> io::Result<()> {
+    match path {
+        Some(path) => write_file(path, write),
+        None => {
+            let stdout = io::stdout();
+            let mut out = stdout.lock();
+            write(&mut out)?;
+            out.flush()
+        }
     }
-    Mesh::new(coords, faces, None, None, false)
+}
+
+/// Print the Physical Pixels redrawn with the rectangles and with the Bounding Boxes
+fn write_overdraw_report(out: &mut dyn Write, report: &OverdrawReport) -> io::Result<()> {
+    writeln!(out, "Virtual Pixels: {}, covered exactly: {}", report.virtual_pixels, report.exact)?;
+    writeln!(out, "Rectangles: {}", report.rectangles)?;
+    writeln!(out, "Physical Pixels in footprints: {}", report.physical_pixels)?;
+    writeln!(out, "Bounding Boxes: {} Physical Pixels, {} overdrawn", report.bounding_box_pixels, report.bounding_box_overdraw())?;
+    writeln!(out, "Rectangles: {} Physical Pixels, {} overdrawn", report.rectangle_pixels, report.rectangle_overdraw())?;
+    writeln!(
+        out, "Saved: {} Physical Pixels ({:.0}%)",
+        report.saved(), report.saved() as f64 / report.bounding_box_pixels.max(1) as f64 * 100.0
+    )
+}
+
+/// Parse `--axis`
+fn axis_arg(args: &ArgMatches) -> VirtualAxis {
+    match args.value_of("axis") {
+        Some("x") => VirtualAxis::X,
+        _ => VirtualAxis::Y,
+    }
+}
+
+/// Validate a binary lookup table and print a summary
+fn inspect(args: &ArgMatches) {
+    let path = args.value_of("FILE").unwrap();
+    let lut = LookupTable::load(path).unwrap_or_else(|err| {
+        eprintln!("{}: {}", path, err);
+        std::process::exit(1);
+    });
+    let size = |width: Option<usize>, height: usize| format!("{}x{}", width.unwrap_or(0), height);
+    println!("Lookup table version {}", nninterpolation::lut::FORMAT_VERSION);
+    println!("Interpolation method: {}", lut.method);
+    println!("Layout: {:?}", lut.layout);
+    println!("Geometry: {:?}", lut.geometry);
+    println!("X_VIRTUAL_GRID / Y_VIRTUAL_GRID: {}", size(lut.x_virtual_grid.first().map(Vec::len), lut.x_virtual_grid.len()));
+    println!("PHYSICAL_TO_VIRTUAL_MAP: {}", size(lut.physical_to_virtual.first().map(Vec::len), lut.physical_to_virtual.len()));
+    println!("VIRTUAL_TO_PHYSICAL_MAP: {}", size(lut.virtual_to_physical.first().map(Vec::len), lut.virtual_to_physical.len()));
+}
+
+#[cfg(feature = "viewer")]
+fn view(args: &ArgMatches) {
+    let axis = axis_arg(args);
+    let geometry = load_geometry(args);
+    let delaunay = load_triangulation(args, &geometry);
+    let methods = load_all_methods(&load_parameters(args), &delaunay);
+    viewer::run(delaunay, geometry, axis, methods);
+}
+
+#[cfg(not(feature = "viewer"))]
+fn view(_args: &ArgMatches) {
+    eprintln!("The viewer isn't available in this build. Rebuild with `--features viewer`");
+    std::process::exit(1);
 }
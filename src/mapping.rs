@@ -0,0 +1,307 @@
+//! Map Physical (x,y) Coordinates on the PineTime Display to Virtual (x,y) Coordinates on the CHIP-8 Emulator Screen, and back.
+//! The quadrant maps are indexed from the centre of the screens, the full screen maps by screen pixels.
+use crate::geometry::DisplayGeometry;
+use cgmath as cg;
+
+/// Bounding Box (left, top, right, bottom) of Physical Pixels
+pub type BoundingBox = (i32, i32, i32, i32);
+
+/// Layout of the generated maps
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize)]
+pub enum MapLayout {
+    /// One quadrant (X >= 0, Y >= 0), indexed by the interpolated grid
+    Quadrant,
+    /// The whole screen in screen coordinates, mirrored from the quadrant
+    FullScreen,
+}
+
+/// Maps between Physical and Virtual Pixels, used by the CHIP-8 Emulator
+#[derive(Serialize)]
+pub struct PixelMaps {
+    pub layout: MapLayout,
+    /// Geometry of the quadrant that the maps were generated from
+    pub geometry: DisplayGeometry,
+    /// Virtual (x,y) Coordinates for each Physical (x,y) Coordinate, indexed by [y][x]
+    pub physical_to_virtual: Vec<Vec<(i32, i32)>>,
+    /// Bounding Box of the Physical (x,y) Coordinates for each Virtual (x,y) Coordinate,
+    /// indexed by [y][x]. `None` if no Physical (x,y) Coordinate maps to the Virtual (x,y) Coordinate.
+    pub virtual_to_physical: Vec<Vec<Option<BoundingBox>>>,
+}
+
+impl PixelMaps {
+    /// Generate the maps from the interpolated Virtual (x,y) grids of the quadrant
+    pub fn new<R: AsRef<[f64]>>(
+        geometry: &DisplayGeometry,
+        x_virtual_grid: &[R],
+        y_virtual_grid: &[R],
+        layout: MapLayout
+    ) -> PixelMaps {
+        match layout {
+            MapLayout::Quadrant => PixelMaps {
+                layout,
+                geometry: *geometry,
+                physical_to_virtual: quadrant_physical_to_virtual(geometry, x_virtual_grid, y_virtual_grid),
+                virtual_to_physical: quadrant_virtual_to_physical(geometry, x_virtual_grid, y_virtual_grid),
+            },
+            MapLayout::FullScreen => {
+                let physical_to_virtual = full_screen_physical_to_virtual(geometry, x_virtual_grid, y_virtual_grid);
+                let virtual_to_physical = full_screen_virtual_to_physical(geometry, &physical_to_virtual);
+                PixelMaps { layout, geometry: *geometry, physical_to_virtual, virtual_to_physical }
+            }
+        }
+    }
+
+    /// Width and height of the Physical Pixels covered by the maps
+    pub fn physical_size(&self) -> (usize, usize) {
+        (self.physical_to_virtual.first().map_or(0, |row| row.len()), self.physical_to_virtual.len())
+    }
+
+    /// Width and height of the Virtual Pixels covered by the maps
+    pub fn virtual_size(&self) -> (usize, usize) {
+        (self.virtual_to_physical.first().map_or(0, |row| row.len()), self.virtual_to_physical.len())
+    }
+
+    /// Print the map of Physical Pixels to Virtual Pixels.
+    /// Used by the CHIP-8 Emulator to decide which Virtual Pixel to fetch the colour value when rendering a Physical Pixel.
+    pub fn print_physical_to_virtual(&self) {
+        println!("PHYSICAL_TO_VIRTUAL_MAP=");
+        print!("[");
+        for row in self.physical_to_virtual.iter() {
+            print!("[");
+            for (x, y) in row.iter() {
+                print!("({},{}),", x, y);
+            }
+            println!("],");
+        }
+        println!("]\n");
+    }
+
+    /// Print the map of Virtual Pixels to Physical Pixels.
+    /// Used by the CHIP-8 Emulator to decide which Physical Pixels to redraw when a Virtual Pixel is updated.
+    pub fn print_virtual_to_physical(&self) {
+        println!("VIRTUAL_TO_PHYSICAL_MAP=");
+        print!("[");
+        for row in self.virtual_to_physical.iter() {
+            print!("[");
+            for bounding_box in row.iter() {
+                if let Some((left, top, right, bottom)) = bounding_box {
+                    print!("({},{},{},{}),", left, top, right, bottom);
+                    /* if left == right && top == bottom {
+                        print!("****");  //  Flag out Virtual Points that map to a single Physical Point
+                    } */
+                } else {
+                    print!("(255,255,255,255),");
+                }
+            }
+            println!("],");
+        }
+        println!("]\n");
+    }
+}
+
+/// Quadrant Virtual Pixel that contains the interpolated Virtual (x,y) Coordinates, counted from
+/// `x_virtual_min` and `y_virtual_min` in steps of the Virtual increments.
+/// The C1 methods overshoot slightly beyond the Virtual screen, so the pixel is clamped to the screen.
+pub fn virtual_pixel(geometry: &DisplayGeometry, x_virtual: f64, y_virtual: f64) -> (i32, i32) {
+    let x_virtual_max = geometry.x_virtual_subdivisions() as i32 - 1;
+    let y_virtual_max = geometry.y_virtual_subdivisions() as i32 - 1;
+    let x_pixel = ((x_virtual - geometry.x_virtual_min) / geometry.x_virtual_increment).floor() as i32;
+    let y_pixel = ((y_virtual - geometry.y_virtual_min) / geometry.y_virtual_increment).floor() as i32;
+    (x_pixel.max(0).min(x_virtual_max), y_pixel.max(0).min(y_virtual_max))
+}
+
+/// For all Physical (x,y) Coordinates, return the corresponding Virtual (x,y) Coordinates.
+fn quadrant_physical_to_virtual<R: AsRef<[f64]>>(
+    geometry: &DisplayGeometry,
+    x_virtual_grid: &[R],
+    y_virtual_grid: &[R]
+) -> Vec<Vec<(i32, i32)>> {
+    (0..geometry.y_physical_subdivisions()).map(|y| {
+        (0..geometry.x_physical_subdivisions()).map(|x| {
+            //  Construct the interpolated Virtual (x,y) Coordinates
+            virtual_pixel(geometry, x_virtual_grid[y].as_ref()[x], y_virtual_grid[y].as_ref()[x])
+        }).collect()
+    }).collect()
+}
+
+/// For all Virtual (x,y) Coordinates, compute the Bounding Box that encloses the corresponding Physical (x,y) Coordinates.
+fn quadrant_virtual_to_physical<R: AsRef<[f64]>>(
+    geometry: &DisplayGeometry,
+    x_virtual_grid: &[R],
+    y_virtual_grid: &[R]
+) -> Vec<Vec<Option<BoundingBox>>> {
+    (0..geometry.y_virtual_subdivisions()).map(|y| {
+        (0..geometry.x_virtual_subdivisions()).map(|x| {
+            //  Convert the normalised (x,y) into Virtual (x,y) Coordinates
+            let pos = geometry.transform_virtual_point(cg::Point2::new(x as f64, y as f64));
+            //  For all Physical (x,y) that interpolate to the Virtual (x,y), find the bounding box
+            get_bounding_box(geometry, x_virtual_grid, y_virtual_grid, pos.x, pos.y)
+                .map(|(left, top, right, bottom)| (left as i32, top as i32, right as i32, bottom as i32))
+        }).collect()
+    }).collect()
+}
+
+/// Mirror the quadrant into the full screen. Physical and Virtual Pixels are mirrored around the same
+/// centre line between two pixels: quadrant x maps to `centre + x` on the right half and `centre - 1 - x`
+/// on the left half, so the quadrant's row and column 0 appear on both sides of the centre lines.
+fn full_screen_physical_to_virtual<R: AsRef<[f64]>>(
+    geometry: &DisplayGeometry,
+    x_virtual_grid: &[R],
+    y_virtual_grid: &[R]
+) -> Vec<Vec<(i32, i32)>> {
+    let x_centre = geometry.x_physical_subdivisions() as i32;
+    let y_centre = geometry.y_physical_subdivisions() as i32;
+    let x_virtual_centre = geometry.x_virtual_subdivisions() as i32;
+    let y_virtual_centre = geometry.y_virtual_subdivisions() as i32;
+    (0..2 * y_centre).map(|screen_y| {
+        let dy = screen_y - y_centre;
+        (0..2 * x_centre).map(|screen_x| {
+            let dx = screen_x - x_centre;
+            let mirror = |d: i32| if d >= 0 { d as usize } else { (-1 - d) as usize };
+            let (qx, qy) = (mirror(dx), mirror(dy));
+
+            //  Convert the interpolated Virtual (x,y) Coordinates into quadrant pixels
+            let (x_pixel, y_pixel) = virtual_pixel(geometry, x_virtual_grid[qy].as_ref()[qx], y_virtual_grid[qy].as_ref()[qx]);
+
+            //  Mirror into the screen
+            (
+                if dx >= 0 { x_virtual_centre + x_pixel } else { x_virtual_centre - 1 - x_pixel },
+                if dy >= 0 { y_virtual_centre + y_pixel } else { y_virtual_centre - 1 - y_pixel }
+            )
+        }).collect()
+    }).collect()
+}
+
+/// For all Virtual Pixels on the screen, compute the Bounding Box that encloses the Physical Pixels mapped to them
+fn full_screen_virtual_to_physical(
+    geometry: &DisplayGeometry,
+    physical_to_virtual: &[Vec<(i32, i32)>]
+) -> Vec<Vec<Option<BoundingBox>>> {
+    let mut result = vec![vec![None; 2 * geometry.x_virtual_subdivisions()]; 2 * geometry.y_virtual_subdivisions()];
+    for (y, row) in physical_to_virtual.iter().enumerate() {
+        for (x, &(x_virtual, y_virtual)) in row.iter().enumerate() {
+            let (x, y) = (x as i32, y as i32);
+            let bounding_box = &mut result[y_virtual as usize][x_virtual as usize];
+            *bounding_box = match *bounding_box {
+                None => Some((x, y, x, y)),
+                Some((left, top, right, bottom)) => Some((left.min(x), top.min(y), right.max(x), bottom.max(y))),
+            };
+        }
+    }
+    result
+}
+
+/// Given a grid of Physical (x,y) Coordinates and their interpolated Virtual (x,y) Coordinates,
+/// find all Physical (x,y) Coordinates that interpolate to (x_virtual,y_virtual).
+/// Return the (left, top, right, bottom) of the Bounding Box that encloses these found points.
+/// x_virtual and y_virtual are truncated to integer during comparison.
+/// Function returns `None` if (x_virtual,y_virtual) was not found.
+pub fn get_bounding_box<R: AsRef<[f64]>>(
+    geometry: &DisplayGeometry,
+    x_virtual_grid: &[R],
+    y_virtual_grid: &[R],
+    x_virtual: f64,
+    y_virtual: f64
+) -> Option<(f64, f64, f64, f64)> {
+    let mut left: f64 = f64::MAX;
+    let mut top: f64 = f64::MAX;
+    let mut right: f64 = f64::MIN;
+    let mut bottom: f64 = f64::MIN;
+    //  For all Physical (x,y) Coordinates...
+    for y in 0..=geometry.y_physical_subdivisions() {
+        for x in 0..=geometry.x_physical_subdivisions() {
+            //  Get the Physical (x,y) Coordinates
+            let pos = geometry.transform_physical_point(cg::Point2::new(x as f64, y as f64));
+
+            //  Get the interpolated Virtual (x,y) Coordinates
+            let x_interpolated = x_virtual_grid[y].as_ref()[x].floor();
+            let y_interpolated = y_virtual_grid[y].as_ref()[x].floor();
+
+            //  Skip if not matching
+            if x_interpolated as u8 != x_virtual as u8 ||
+                y_interpolated as u8 != y_virtual as u8 { continue; }
+
+            //  Find the Bounding Box of the Physical (x,y) Coordinates
+            if pos.x < left   { left   = pos.x; }
+            if pos.y < top    { top    = pos.y; }
+            if pos.x > right  { right  = pos.x; }
+            if pos.y > bottom { bottom = pos.y; }
+        }
+    };
+    if left < f64::MAX && top < f64::MAX &&
+        right > f64::MIN && bottom > f64::MIN {  //  (x_virtual,y_virtual) found
+            Some((left.floor(), top.floor(), right.floor(), bottom.floor()))
+    } else { None }  //  (x_virtual,y_virtual) not found
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::delaunay_creation::{builtin_control_points, triangulation_from_control_points};
+    use crate::interpolation::interpolation_methods::NaturalNeighborInterpolation;
+    use crate::interpolation::Grid;
+    use crate::VirtualAxis;
+
+    #[test]
+    fn virtual_pixels_count_from_the_virtual_min() {
+        let geometry = DisplayGeometry {
+            x_virtual_min: 4.0,
+            x_virtual_max: 36.0,
+            x_virtual_increment: 2.0,
+            y_virtual_min: -2.0,
+            y_virtual_max: 14.0,
+            y_virtual_increment: 0.5,
+            ..DisplayGeometry::default()
+        };
+        assert_eq!(virtual_pixel(&geometry, 9.0, 0.75), (2, 5));
+        assert_eq!(virtual_pixel(&geometry, 0.0, 20.0), (0, 31));
+
+        //  The quadrant and the bottom right of the full screen agree
+        let delaunay = triangulation_from_control_points(&builtin_control_points());
+        let grid = Grid::from_delaunay_interpolation(&NaturalNeighborInterpolation, &delaunay, &geometry);
+        let (x_virtual_grid, y_virtual_grid) = (grid.values(VirtualAxis::X), grid.values(VirtualAxis::Y));
+        let quadrant = PixelMaps::new(&geometry, x_virtual_grid, y_virtual_grid, MapLayout::Quadrant);
+        let full_screen = PixelMaps::new(&geometry, x_virtual_grid, y_virtual_grid, MapLayout::FullScreen);
+        let (x_centre, y_centre) = quadrant.physical_size();
+        let (x_virtual_centre, y_virtual_centre) = quadrant.virtual_size();
+        for (y, row) in quadrant.physical_to_virtual.iter().enumerate() {
+            for (x, &(x_virtual, y_virtual)) in row.iter().enumerate() {
+                assert_eq!(
+                    full_screen.physical_to_virtual[y_centre + y][x_centre + x],
+                    (x_virtual_centre as i32 + x_virtual, y_virtual_centre as i32 + y_virtual)
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn full_screen_is_mirror_symmetric() {
+        let geometry = DisplayGeometry::default();
+        let delaunay = triangulation_from_control_points(&builtin_control_points());
+        let grid = Grid::from_delaunay_interpolation(&NaturalNeighborInterpolation, &delaunay, &geometry);
+        let maps = PixelMaps::new(&geometry, grid.values(VirtualAxis::X), grid.values(VirtualAxis::Y), MapLayout::FullScreen);
+        let (physical_width, physical_height) = maps.physical_size();
+        let (virtual_width, virtual_height) = maps.virtual_size();
+        let map = &maps.physical_to_virtual;
+        for y in 0..physical_height {
+            for x in 0..physical_width {
+                let (x_virtual, y_virtual) = map[y][x];
+                let (mirror_x, mirror_y) = (physical_width - 1 - x, physical_height - 1 - y);
+                assert_eq!(map[y][mirror_x], (virtual_width as i32 - 1 - x_virtual, y_virtual), "({}, {})", x, y);
+                assert_eq!(map[mirror_y][x], (x_virtual, virtual_height as i32 - 1 - y_virtual), "({}, {})", x, y);
+            }
+        }
+
+        //  Mirrored Virtual Pixels cover the same number of Physical Pixels
+        let mut counts = vec![vec![0; virtual_width]; virtual_height];
+        for &(x_virtual, y_virtual) in map.iter().flat_map(|row| row.iter()) {
+            counts[y_virtual as usize][x_virtual as usize] += 1;
+        }
+        for y in 0..virtual_height {
+            for x in 0..virtual_width {
+                assert_eq!(counts[y][x], counts[y][virtual_width - 1 - x]);
+                assert_eq!(counts[y][x], counts[virtual_height - 1 - y][x]);
+            }
+        }
+    }
+}
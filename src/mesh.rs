@@ -0,0 +1,224 @@
+//! Export the triangulation and the interpolated surfaces as OBJ, PLY or STL meshes, for external 3D tools.
+//! Vertices are Physical (x,y) Coordinates with the Virtual Coordinate on one axis as the height,
+//! the same meshes that the kiss3d demo renders.
+use crate::control_points::VirtualAxis;
+use crate::delaunay_creation::Delaunay;
+use crate::get_triangulation_triangles;
+use crate::interpolation::Grid;
+use nalgebra as na;
+use std::io::{self, Write};
+use std::path::Path;
+
+/// Mesh file formats
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum MeshFormat {
+    /// Wavefront OBJ, with vertex normals if the mesh has them
+    Obj,
+    /// ASCII PLY, with vertex normals if the mesh has them
+    Ply,
+    /// Binary STL, with face normals
+    Stl,
+}
+
+impl MeshFormat {
+    /// Return the format for the extension of `path`, e.g. `surface.obj`
+    pub fn from_path<P: AsRef<Path>>(path: P) -> Option<MeshFormat> {
+        let extension = path.as_ref().extension()?.to_str()?.to_lowercase();
+        match extension.as_str() {
+            "obj" => Some(MeshFormat::Obj),
+            "ply" => Some(MeshFormat::Ply),
+            "stl" => Some(MeshFormat::Stl),
+            _ => None,
+        }
+    }
+}
+
+/// Triangle mesh with optional vertex normals
+pub struct Mesh {
+    pub vertices: Vec<na::Point3<f32>>,
+    pub faces: Vec<na::Point3<u32>>,
+    /// One normal per vertex
+    pub normals: Option<Vec<na::Vector3<f32>>>,
+}
+
+impl Mesh {
+    /// Mesh of the triangulation, with the normals that `get_normals` shows
+    pub fn from_triangulation(delaunay: &Delaunay, axis: VirtualAxis) -> Mesh {
+        let (vertices, faces) = get_triangulation_triangles(delaunay, axis);
+        let normals = delaunay.vertices()
+            .map(|vertex| {
+                let normal = vertex.normal(axis);
+                na::Vector3::new(normal.x as f32, normal.y as f32, normal.z as f32)
+            })
+            .collect();
+        Mesh { vertices, faces, normals: Some(normals) }
+    }
+
+    /// Mesh of the interpolated surface, from `Grid::get_triangles`
+    pub fn from_grid(grid: &Grid, axis: VirtualAxis) -> Mesh {
+        let (vertices, faces) = grid.get_triangles(axis);
+        Mesh { vertices, faces, normals: None }
+    }
+
+    /// Write the mesh in `format`
+    pub fn write<W: Write + ?Sized>(&self, out: &mut W, format: MeshFormat) -> io::Result<()> {
+        match format {
+            MeshFormat::Obj => self.write_obj(out),
+            MeshFormat::Ply => self.write_ply(out),
+            MeshFormat::Stl => self.write_stl(out),
+        }
+    }
+
+    /// Write the mesh as Wavefront OBJ. Indices start from 1.
+    pub fn write_obj<W: Write + ?Sized>(&self, out: &mut W) -> io::Result<()> {
+        writeln!(out, "# Generated by nninterpolation {}", env!("CARGO_PKG_VERSION"))?;
+        for vertex in self.vertices.iter() {
+            writeln!(out, "v {} {} {}", vertex.x, vertex.y, vertex.z)?;
+        }
+        if let Some(normals) = &self.normals {
+            for normal in normals.iter() {
+                writeln!(out, "vn {} {} {}", normal.x, normal.y, normal.z)?;
+            }
+        }
+        for face in self.faces.iter() {
+            let (a, b, c) = (face.x as usize + 1, face.y as usize + 1, face.z as usize + 1);
+            if self.normals.is_some() {
+                writeln!(out, "f {}//{} {}//{} {}//{}", a, a, b, b, c, c)?;
+            } else {
+                writeln!(out, "f {} {} {}", a, b, c)?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Write the mesh as ASCII PLY
+    pub fn write_ply<W: Write + ?Sized>(&self, out: &mut W) -> io::Result<()> {
+        writeln!(out, "ply")?;
+        writeln!(out, "format ascii 1.0")?;
+        writeln!(out, "comment Generated by nninterpolation {}", env!("CARGO_PKG_VERSION"))?;
+        writeln!(out, "element vertex {}", self.vertices.len())?;
+        writeln!(out, "property float x\nproperty float y\nproperty float z")?;
+        if self.normals.is_some() {
+            writeln!(out, "property float nx\nproperty float ny\nproperty float nz")?;
+        }
+        writeln!(out, "element face {}", self.faces.len())?;
+        writeln!(out, "property list uchar uint vertex_indices")?;
+        writeln!(out, "end_header")?;
+        for (index, vertex) in self.vertices.iter().enumerate() {
+            write!(out, "{} {} {}", vertex.x, vertex.y, vertex.z)?;
+            if let Some(normals) = &self.normals {
+                write!(out, " {} {} {}", normals[index].x, normals[index].y, normals[index].z)?;
+            }
+            writeln!(out)?;
+        }
+        for face in self.faces.iter() {
+            writeln!(out, "3 {} {} {}", face.x, face.y, face.z)?;
+        }
+        Ok(())
+    }
+
+    /// Write the mesh as binary STL, computing the normal of each face
+    pub fn write_stl<W: Write + ?Sized>(&self, out: &mut W) -> io::Result<()> {
+        let mut header = [0u8; 80];
+        let title = format!("nninterpolation {}", env!("CARGO_PKG_VERSION"));
+        header[..title.len()].copy_from_slice(title.as_bytes());
+        out.write_all(&header)?;
+        out.write_all(&(self.faces.len() as u32).to_le_bytes())?;
+        for face in self.faces.iter() {
+            let corners = [
+                self.vertices[face.x as usize],
+                self.vertices[face.y as usize],
+                self.vertices[face.z as usize],
+            ];
+            let normal = (corners[1] - corners[0]).cross(&(corners[2] - corners[0]));
+            let normal = normal.try_normalize(0.0).unwrap_or_else(na::Vector3::zeros);
+            for value in normal.iter().chain(corners.iter().flat_map(|corner| corner.coords.iter())) {
+                out.write_all(&value.to_le_bytes())?;
+            }
+            out.write_all(&[0, 0])?;  //  Attribute byte count
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Mesh, MeshFormat};
+    use crate::control_points::VirtualAxis;
+    use crate::delaunay_creation::{builtin_control_points, triangulation_from_control_points};
+    use crate::geometry::DisplayGeometry;
+    use crate::interpolation::interpolation_methods::BarycentricInterpolation;
+    use crate::interpolation::Grid;
+
+    #[test]
+    fn fine_grid_indices_do_not_wrap() {
+        let delaunay = triangulation_from_control_points(&builtin_control_points());
+        let geometry = DisplayGeometry {
+            x_physical_increment: 0.25,
+            y_physical_increment: 0.25,
+            ..DisplayGeometry::default()
+        };
+        let grid = Grid::from_delaunay_interpolation(&BarycentricInterpolation, &delaunay, &geometry);
+        let mesh = Mesh::from_grid(&grid, VirtualAxis::X);
+        assert!(mesh.vertices.len() > 65536);
+        let max_index = mesh.faces.iter().flat_map(|face| face.iter()).max().cloned().unwrap();
+        assert_eq!(max_index as usize, mesh.vertices.len() - 1);
+    }
+
+    fn triangulation_mesh() -> Mesh {
+        Mesh::from_triangulation(&triangulation_from_control_points(&builtin_control_points()), VirtualAxis::X)
+    }
+
+    fn write(mesh: &Mesh, format: MeshFormat) -> Vec<u8> {
+        let mut out = Vec::new();
+        mesh.write(&mut out, format).unwrap();
+        out
+    }
+
+    fn count_lines(text: &str, prefix: &str) -> usize {
+        text.lines().filter(|line| line.starts_with(prefix)).count()
+    }
+
+    #[test]
+    fn obj_has_a_line_per_vertex_normal_and_face() {
+        let mesh = triangulation_mesh();
+        let obj = String::from_utf8(write(&mesh, MeshFormat::Obj)).unwrap();
+        assert_eq!(count_lines(&obj, "v "), mesh.vertices.len());
+        assert_eq!(count_lines(&obj, "vn "), mesh.vertices.len());
+        assert_eq!(count_lines(&obj, "f "), mesh.faces.len());
+        let face = &mesh.faces[0];
+        let first_face = format!("f {}//{} {}//{} {}//{}",
+                                 face.x + 1, face.x + 1, face.y + 1, face.y + 1, face.z + 1, face.z + 1);
+        assert!(obj.lines().any(|line| line == first_face));
+    }
+
+    #[test]
+    fn ply_header_declares_the_elements() {
+        let mesh = triangulation_mesh();
+        let ply = String::from_utf8(write(&mesh, MeshFormat::Ply)).unwrap();
+        let (header, body) = ply.split_at(ply.find("end_header\n").unwrap() + "end_header\n".len());
+        let header: Vec<&str> = header.lines().filter(|line| !line.starts_with("comment")).collect();
+        assert_eq!(header, [
+            "ply",
+            "format ascii 1.0",
+            &format!("element vertex {}", mesh.vertices.len()),
+            "property float x", "property float y", "property float z",
+            "property float nx", "property float ny", "property float nz",
+            &format!("element face {}", mesh.faces.len()),
+            "property list uchar uint vertex_indices",
+            "end_header",
+        ]);
+        let lines: Vec<&str> = body.lines().collect();
+        assert_eq!(lines.len(), mesh.vertices.len() + mesh.faces.len());
+        assert!(lines[..mesh.vertices.len()].iter().all(|line| line.split(' ').count() == 6));
+        assert!(lines[mesh.vertices.len()..].iter().all(|line| line.starts_with("3 ")));
+    }
+
+    #[test]
+    fn stl_has_50_bytes_per_face() {
+        let mesh = triangulation_mesh();
+        let stl = write(&mesh, MeshFormat::Stl);
+        assert_eq!(stl.len(), 84 + 50 * mesh.faces.len());
+        assert_eq!(stl[80..84], (mesh.faces.len() as u32).to_le_bytes());
+    }
+}
@@ -0,0 +1,270 @@
+//! Parameters of the interpolation methods, configurable at runtime from the CLI or a config file.
+//! The config file has one `key = value` per line, like the geometry file, e.g.
+//! ```text
+//! # Smoother Sibson surface, Gaussian radial basis function
+//! smoothness = 2.0
+//! rbf_kernel = gaussian
+//! rbf_shape  = 0.05
+//! bicubic_lattice = 5x5
+//! ```
+//! Keys are the field names of `MethodParameters`. Missing keys keep their default values from constants.rs.
+use crate::constants::*;
+use crate::delaunay_creation::Delaunay;
+use crate::interpolation::interpolation_methods::{
+    BarycentricInterpolation, BicubicLatticeInterpolation, CloughTocherInterpolation, FarinC1Interpolation,
+    InverseDistanceWeighting, NaturalNeighborInterpolation, RadialBasisKernel, SibsonC1Interpolation,
+    ThinPlateSplineInterpolation,
+};
+use crate::interpolation::InterpolationMethod;
+use std::error::Error;
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::Path;
+
+/// Names of the interpolation methods accepted by `MethodParameters::method`, in the order of `all_methods`
+pub const METHOD_NAMES: &[&str] = &["barycentric", "nn", "sibson", "farin", "clough-tocher", "bicubic", "idw", "tps"];
+
+/// Parameters for creating the interpolation methods
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct MethodParameters {
+    /// Smoothness factor of Sibson's C1 interpolation
+    pub smoothness: f64,
+
+    /// Power of the distance and number of nearest control points for inverse distance weighting, 0 for all
+    pub idw_power: f64,
+    pub idw_neighbours: usize,
+
+    /// Radial basis function, its shape parameter ε and the regularisation of the spline system
+    pub rbf_kernel: RadialBasisKernel,
+    pub rbf_shape: f64,
+    pub rbf_regularisation: f64,
+
+    /// Columns and rows of the control point lattice for bicubic interpolation, or `None` to detect them
+    pub bicubic_lattice: Option<(usize, usize)>,
+}
+
+/// Error returned when the parameters can't be loaded or are invalid
+#[derive(Debug)]
+pub enum ParameterError {
+    /// The config file couldn't be read
+    Io(io::Error),
+    /// A line couldn't be parsed. `line` starts from 1.
+    Malformed { line: usize, reason: String },
+    /// A parameter is out of range, e.g. a negative power
+    Invalid(String),
+    /// The method is unknown, or can't be created for the control points, e.g. a singular spline
+    Method(String),
+}
+
+impl fmt::Display for ParameterError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ParameterError::Io(err) => write!(f, "failed to read parameters: {}", err),
+            ParameterError::Malformed { line, reason } => write!(f, "line {}: {}", line, reason),
+            ParameterError::Invalid(reason) => write!(f, "invalid parameters: {}", reason),
+            ParameterError::Method(reason) => write!(f, "invalid method: {}", reason),
+        }
+    }
+}
+
+impl Error for ParameterError {}
+
+impl From<io::Error> for ParameterError {
+    fn from(err: io::Error) -> ParameterError {
+        ParameterError::Io(err)
+    }
+}
+
+impl Default for MethodParameters {
+    fn default() -> MethodParameters {
+        MethodParameters {
+            smoothness: SIBSON_SMOOTHNESS,
+            idw_power: IDW_POWER,
+            idw_neighbours: IDW_NEIGHBOURS,
+            rbf_kernel: RadialBasisKernel::ThinPlate,
+            rbf_shape: RBF_SHAPE,
+            rbf_regularisation: RBF_REGULARISATION,
+            bicubic_lattice: None,
+        }
+    }
+}
+
+impl MethodParameters {
+    /// Load the parameters from the config file at `path`, starting from the defaults
+    pub fn load<P: AsRef<Path>>(path: P) -> Result<MethodParameters, ParameterError> {
+        let text = fs::read_to_string(path)?;
+        let mut parameters = MethodParameters::default();
+        parameters.parse(&text)?;
+        Ok(parameters)
+    }
+
+    /// Update the parameters with the `key = value` lines in `text`
+    pub fn parse(&mut self, text: &str) -> Result<(), ParameterError> {
+        for (index, line) in text.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') { continue; }
+            let malformed = |reason: String| ParameterError::Malformed { line: index + 1, reason };
+            let mut parts = line.splitn(2, '=');
+            let key = parts.next().unwrap_or("").trim();
+            let value = parts.next()
+                .ok_or_else(|| malformed(format!("expected `key = value`, found `{}`", line)))?
+                .trim();
+            self.set(key, value).map_err(malformed)?;
+        }
+        self.validate()
+    }
+
+    /// Set the field named `key` from its text, e.g. `gaussian` for `rbf_kernel` or `5x5` for `bicubic_lattice`
+    pub fn set(&mut self, key: &str, value: &str) -> Result<(), String> {
+        let number = || value.parse::<f64>().map_err(|_| format!("{} is not a number: `{}`", key, value));
+        let count = || value.parse::<usize>().map_err(|_| format!("{} is not a count: `{}`", key, value));
+        match key {
+            "smoothness" => self.smoothness = number()?,
+            "idw_power" => self.idw_power = number()?,
+            "idw_neighbours" => self.idw_neighbours = count()?,
+            "rbf_kernel" => {
+                self.rbf_kernel = RadialBasisKernel::from_name(value).ok_or_else(|| format!(
+                    "rbf_kernel must be one of {}, found `{}`", RadialBasisKernel::NAMES.join(", "), value
+                ))?
+            }
+            "rbf_shape" => self.rbf_shape = number()?,
+            "rbf_regularisation" => self.rbf_regularisation = number()?,
+            "bicubic_lattice" => self.bicubic_lattice = Some(parse_lattice(value)?),
+            _ => return Err(format!("unknown key `{}`", key)),
+        }
+        Ok(())
+    }
+
+    /// Check that the parameters are finite and in range
+    pub fn validate(&self) -> Result<(), ParameterError> {
+        let invalid = |reason: &str| Err(ParameterError::Invalid(reason.to_string()));
+        if !(self.smoothness.is_finite() && self.smoothness > 0.0) {
+            return invalid("smoothness must be positive");
+        }
+        if !(self.idw_power.is_finite() && self.idw_power > 0.0) {
+            return invalid("idw_power must be positive");
+        }
+        if !(self.rbf_shape.is_finite() && self.rbf_shape > 0.0) {
+            return invalid("rbf_shape must be positive");
+        }
+        if !(self.rbf_regularisation.is_finite() && self.rbf_regularisation >= 0.0) {
+            return invalid("rbf_regularisation must not be negative");
+        }
+        if self.bicubic_lattice.is_some_and(|(columns, rows)| columns < 2 || rows < 2) {
+            return invalid("bicubic_lattice must have at least 2 columns and 2 rows");
+        }
+        Ok(())
+    }
+
+    /// Create the interpolation method named `name`, one of `METHOD_NAMES`, with these parameters.
+    /// Methods that are fitted once, like the spline, are fitted to the control points of `delaunay`.
+    pub fn method(&self, name: &str, delaunay: &Delaunay) -> Result<Box<dyn InterpolationMethod>, ParameterError> {
+        let method: Box<dyn InterpolationMethod> = match name {
+            "barycentric" => Box::new(BarycentricInterpolation),
+            "nn" => Box::new(NaturalNeighborInterpolation),
+            "sibson" => Box::new(SibsonC1Interpolation { smoothness: self.smoothness }),
+            "farin" => Box::new(FarinC1Interpolation),
+            "clough-tocher" => Box::new(CloughTocherInterpolation),
+            "bicubic" => Box::new(
+                BicubicLatticeInterpolation::new(delaunay, self.bicubic_lattice)
+                    .map_err(|reason| ParameterError::Method(format!("{}: {}", name, reason)))?
+            ),
+            "idw" => Box::new(InverseDistanceWeighting { power: self.idw_power, neighbours: self.idw_neighbours }),
+            "tps" => Box::new(
+                ThinPlateSplineInterpolation::new(delaunay, self.rbf_kernel, self.rbf_shape, self.rbf_regularisation)
+                    .map_err(|err| ParameterError::Method(format!("{}: {}", name, err)))?
+            ),
+            _ => {
+                return Err(ParameterError::Method(
+                    format!("unknown method `{}`, expected one of {}", name, METHOD_NAMES.join(", "))
+                ))
+            }
+        };
+        Ok(method)
+    }
+
+    /// Create every interpolation method for the control points of `delaunay`, in the order of `METHOD_NAMES`
+    pub fn all_methods(&self, delaunay: &Delaunay) -> Result<Vec<Box<dyn InterpolationMethod>>, ParameterError> {
+        METHOD_NAMES.iter().map(|name| self.method(name, delaunay)).collect()
+    }
+}
+
+/// Parse a lattice size like `5x5` into (columns, rows)
+fn parse_lattice(value: &str) -> Result<(usize, usize), String> {
+    let mut parts = value.splitn(2, 'x');
+    let mut next = || parts.next().and_then(|part| part.trim().parse::<usize>().ok());
+    match (next(), next()) {
+        (Some(columns), Some(rows)) => Ok((columns, rows)),
+        _ => Err(format!("bicubic_lattice must be COLUMNSxROWS, e.g. 5x5, found `{}`", value)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn set_parses_each_key() {
+        let mut parameters = MethodParameters::default();
+        parameters.set("smoothness", "2.5").unwrap();
+        parameters.set("idw_power", "3").unwrap();
+        parameters.set("idw_neighbours", "8").unwrap();
+        parameters.set("rbf_kernel", "gaussian").unwrap();
+        parameters.set("rbf_shape", "0.05").unwrap();
+        parameters.set("rbf_regularisation", "0.001").unwrap();
+        parameters.set("bicubic_lattice", "5x4").unwrap();
+        assert_eq!(parameters, MethodParameters {
+            smoothness: 2.5,
+            idw_power: 3.0,
+            idw_neighbours: 8,
+            rbf_kernel: RadialBasisKernel::Gaussian,
+            rbf_shape: 0.05,
+            rbf_regularisation: 0.001,
+            bicubic_lattice: Some((5, 4)),
+        });
+    }
+
+    #[test]
+    fn set_rejects_bad_values_and_unknown_keys() {
+        let mut parameters = MethodParameters::default();
+        assert!(parameters.set("smoothness", "smooth").is_err());
+        assert!(parameters.set("idw_neighbours", "-1").is_err());
+        assert!(parameters.set("rbf_kernel", "cubic").is_err());
+        assert!(parameters.set("bicubic_lattice", "5").is_err());
+        assert!(parameters.set("bicubic_lattice", "5xfive").is_err());
+        assert!(parameters.set("smoothnes", "1").is_err());
+        assert_eq!(parameters, MethodParameters::default());
+    }
+
+    #[test]
+    fn validate_rejects_out_of_range_values() {
+        assert!(MethodParameters::default().validate().is_ok());
+        let invalid = [
+            MethodParameters { smoothness: 0.0, ..MethodParameters::default() },
+            MethodParameters { smoothness: f64::NAN, ..MethodParameters::default() },
+            MethodParameters { idw_power: -1.0, ..MethodParameters::default() },
+            MethodParameters { rbf_shape: 0.0, ..MethodParameters::default() },
+            MethodParameters { rbf_regularisation: -0.1, ..MethodParameters::default() },
+            MethodParameters { bicubic_lattice: Some((1, 5)), ..MethodParameters::default() },
+        ];
+        for parameters in invalid.iter() {
+            match parameters.validate() {
+                Err(ParameterError::Invalid(_)) => {}
+                result => panic!("{:?} validated as {:?}", parameters, result),
+            }
+        }
+        let zero_regularisation = MethodParameters { rbf_regularisation: 0.0, ..MethodParameters::default() };
+        assert!(zero_regularisation.validate().is_ok());
+    }
+
+    #[test]
+    fn parse_reports_the_line_of_a_bad_value() {
+        let mut parameters = MethodParameters::default();
+        match parameters.parse("# comment\nsmoothness = 2\n\nidw_power = fast\n") {
+            Err(ParameterError::Malformed { line, .. }) => assert_eq!(line, 4),
+            result => panic!("parsed as {:?}", result),
+        }
+        assert_eq!(parameters.smoothness, 2.0);
+    }
+}
@@ -0,0 +1,104 @@
+//! Generate the control points by wrapping the flat CHIP-8 Emulator Screen onto a sphere, as seen on the PineTime Display.
+//! Replaces the spreadsheet that produced the built-in control points, so that the bulge may be changed with one parameter.
+//! The sphere is centred on the centre of the screens, so the control points are generated for the quadrant only.
+//!
+//! Each Virtual (x,y) Coordinate is first scaled to the Physical extents, so that the flat screen covers the quadrant.
+//! The point is then laid on the sphere at the same arc length from the centre of the screen, and projected back
+//! onto the display. A point at distance `d` from the centre lands at `sin(d * curvature) / curvature`.
+//! Finally each axis is stretched so that the screen still touches the edges of the display along the X and Y axes,
+//! which pulls in the corners.
+use crate::constants::*;
+use crate::control_points::ControlPoint;
+use crate::geometry::{DisplayGeometry, GeometryError};
+use cgmath as cg;
+use std::f64::consts::FRAC_PI_2;
+
+/// Spherical projection of the Virtual (x,y) Coordinates onto the Physical (x,y) Coordinates
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct SphericalProjection {
+    /// Curvature of the sphere, i.e. 1 / radius in Physical pixels. 0 for a flat screen.
+    pub curvature: f64,
+    /// Number of control points along the Virtual X and Y axes, including both ends
+    pub x_lattice: usize,
+    pub y_lattice: usize,
+}
+
+impl Default for SphericalProjection {
+    /// Approximates the bulge of the built-in control points
+    fn default() -> SphericalProjection {
+        SphericalProjection::with_radius(SPHERE_RADIUS, X_LATTICE, Y_LATTICE)
+    }
+}
+
+impl SphericalProjection {
+    /// Projection onto a sphere of `radius` Physical pixels
+    pub fn with_radius(radius: f64, x_lattice: usize, y_lattice: usize) -> SphericalProjection {
+        SphericalProjection { curvature: 1.0 / radius, x_lattice, y_lattice }
+    }
+
+    /// Check that the lattice has both ends of each axis, and that the sphere doesn't fold the screen over itself
+    pub fn validate(&self, geometry: &DisplayGeometry) -> Result<(), GeometryError> {
+        if self.x_lattice < 2 || self.y_lattice < 2 {
+            return Err(GeometryError::Invalid("lattice needs at least 2 control points per axis".to_string()));
+        }
+        //  Beyond a quarter circle from the centre, points move back towards the centre
+        let corner = geometry.x_physical_max - geometry.x_physical_min;
+        let corner = corner.hypot(geometry.y_physical_max - geometry.y_physical_min);
+        if !(self.curvature.is_finite() && self.curvature >= 0.0 && self.curvature * corner <= FRAC_PI_2) {
+            return Err(GeometryError::Invalid(format!(
+                "sphere radius must be at least {:.1} to cover the corner of the screen",
+                corner / FRAC_PI_2
+            )));
+        }
+        Ok(())
+    }
+
+    /// Given Virtual (x,y) Coordinates, return the Physical (x,y) Coordinates where they appear on the sphere
+    pub fn project(&self, geometry: &DisplayGeometry, virtual_point: cg::Point2<f64>) -> cg::Point2<f64> {
+        let width = geometry.x_physical_max - geometry.x_physical_min;
+        let height = geometry.y_physical_max - geometry.y_physical_min;
+
+        //  Scale the flat screen to the Physical extents
+        let x = (virtual_point.x - geometry.x_virtual_min) / geometry.virtual_width() * width;
+        let y = (virtual_point.y - geometry.y_virtual_min) / geometry.virtual_height() * height;
+
+        //  Wrap onto the sphere, then stretch each axis back to the edges of the display
+        let distance = x.hypot(y);
+        let factor = if distance > 0.0 { self.arc(distance) / distance } else { 1.0 };
+        cg::Point2::new(
+            geometry.x_physical_min + x * factor * width / self.arc(width),
+            geometry.y_physical_min + y * factor * height / self.arc(height)
+        )
+    }
+
+    /// Return the control points on a lattice of evenly spaced Virtual (x,y) Coordinates
+    pub fn control_points(&self, geometry: &DisplayGeometry) -> Result<Vec<ControlPoint>, GeometryError> {
+        self.validate(geometry)?;
+        let mut points = Vec::new();
+        for y in 0..self.y_lattice {
+            for x in 0..self.x_lattice {
+                let virtual_point = cg::Point2::new(
+                    geometry.x_virtual_min + geometry.virtual_width() * x as f64 / (self.x_lattice - 1) as f64,
+                    geometry.y_virtual_min + geometry.virtual_height() * y as f64 / (self.y_lattice - 1) as f64
+                );
+                let physical_point = self.project(geometry, virtual_point);
+                points.push(ControlPoint {
+                    x_physical: physical_point.x,
+                    y_physical: physical_point.y,
+                    x_virtual: virtual_point.x,
+                    y_virtual: virtual_point.y,
+                });
+            }
+        }
+        Ok(points)
+    }
+
+    /// Distance from the centre of the display for a point at arc length `distance` on the sphere
+    fn arc(&self, distance: f64) -> f64 {
+        if self.curvature == 0.0 {
+            distance
+        } else {
+            (distance * self.curvature).sin() / self.curvature
+        }
+    }
+}
@@ -0,0 +1,262 @@
+//! Cover the Physical Pixels of each Virtual Pixel with a few rectangles instead of one Bounding Box.
+//! Near the curved edges, the Bounding Box from `get_bounding_box` contains many Physical Pixels that belong to
+//! neighbouring Virtual Pixels, and redrawing them wastes SPI bandwidth. Here the rows of each footprint are stacked
+//! into rectangles that cover exactly its Physical Pixels. If that takes more than `max_rectangles`, the pair of
+//! rectangles that adds the fewest extra Physical Pixels is merged until the limit is met.
+//! In the quadrant, each interpolated grid point covers the Physical Pixels between its scaled edge and the next one,
+//! so the footprints are converted to Physical Pixels before they are covered and counted.
+use crate::export::{to_u8, ByteBoundingBox, NO_BOUNDING_BOX};
+use crate::mapping::{BoundingBox, MapLayout, PixelMaps};
+use cgmath as cg;
+use serde_json;
+use std::io::{self, Write};
+
+/// Rectangles (left, top, right, bottom) of Physical Pixels for each Virtual Pixel
+#[derive(Serialize)]
+pub struct RectangleCover {
+    pub layout: MapLayout,
+    /// Most rectangles per Virtual Pixel
+    pub max_rectangles: usize,
+    /// Rectangles for each Virtual (x,y) Coordinate, indexed by [y][x], in the same coordinates as
+    /// `PixelMaps::virtual_to_physical`. Empty if no Physical Pixel maps to the Virtual Pixel.
+    pub virtual_to_physical: Vec<Vec<Vec<BoundingBox>>>,
+    pub report: OverdrawReport,
+}
+
+/// Physical Pixels redrawn when every Virtual Pixel is updated once
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize)]
+pub struct OverdrawReport {
+    /// Physical Pixels in the footprints of the Virtual Pixels, i.e. the minimum to redraw
+    pub physical_pixels: usize,
+    /// Physical Pixels redrawn with one Bounding Box per Virtual Pixel
+    pub bounding_box_pixels: usize,
+    /// Physical Pixels redrawn with the rectangles
+    pub rectangle_pixels: usize,
+    pub rectangles: usize,
+    /// Virtual Pixels whose rectangles cover exactly their footprint
+    pub exact: usize,
+    pub virtual_pixels: usize,
+}
+
+impl OverdrawReport {
+    /// Physical Pixels redrawn outside the footprints with a Bounding Box
+    pub fn bounding_box_overdraw(&self) -> usize {
+        self.bounding_box_pixels - self.physical_pixels
+    }
+
+    /// Physical Pixels redrawn outside the footprints with the rectangles
+    pub fn rectangle_overdraw(&self) -> usize {
+        self.rectangle_pixels - self.physical_pixels
+    }
+
+    /// Physical Pixels not redrawn by using the rectangles instead of the Bounding Boxes
+    pub fn saved(&self) -> usize {
+        self.bounding_box_pixels - self.rectangle_pixels
+    }
+}
+
+impl RectangleCover {
+    /// Cover the footprint of each Virtual Pixel in the maps with up to `max_rectangles` rectangles
+    pub fn new(maps: &PixelMaps, max_rectangles: usize) -> RectangleCover {
+        let (virtual_width, virtual_height) = maps.virtual_size();
+
+        //  Collect the runs of Physical Pixels in each row, by Virtual Pixel
+        let mut runs: Vec<Vec<Vec<BoundingBox>>> = vec![vec![Vec::new(); virtual_width]; virtual_height];
+        for (y, row) in maps.physical_to_virtual.iter().enumerate() {
+            let mut start = 0;
+            for x in 1..=row.len() {
+                if x < row.len() && row[x] == row[start] { continue; }
+                let (virtual_x, virtual_y) = row[start];
+                let footprint = runs.get_mut(virtual_y as usize).and_then(|row| row.get_mut(virtual_x as usize));
+                if let (Some(footprint), Some(run)) = (footprint, to_physical(maps, (start as i32, y as i32, x as i32 - 1, y as i32))) {
+                    footprint.push(run);
+                }
+                start = x;
+            }
+        }
+
+        let mut report = OverdrawReport {
+            physical_pixels: 0,
+            bounding_box_pixels: 0,
+            rectangle_pixels: 0,
+            rectangles: 0,
+            exact: 0,
+            virtual_pixels: 0,
+        };
+        let virtual_to_physical = runs.iter().map(|row| {
+            row.iter().map(|footprint| {
+                if footprint.is_empty() { return Vec::new(); }
+                let rectangles = cover_footprint(footprint, max_rectangles.max(1));
+                let physical_pixels: usize = footprint.iter().map(area).sum();
+                let rectangle_pixels: usize = rectangles.iter().map(area).sum();
+                report.physical_pixels += physical_pixels;
+                report.bounding_box_pixels += area(&enclose(footprint.iter()));
+                report.rectangle_pixels += rectangle_pixels;
+                report.rectangles += rectangles.len();
+                report.virtual_pixels += 1;
+                if rectangle_pixels == physical_pixels { report.exact += 1; }
+                rectangles
+            }).collect()
+        }).collect();
+        RectangleCover { layout: maps.layout, max_rectangles, virtual_to_physical, report }
+    }
+
+    /// Write the rectangles as JSON
+    pub fn write_json<W: Write + ?Sized>(&self, out: &mut W) -> io::Result<()> {
+        serde_json::to_writer(&mut *out, self)?;
+        writeln!(out)
+    }
+
+    /// Write the rectangles as a Rust static, ready to `include!`:
+    /// `VIRTUAL_TO_PHYSICAL_RECTANGLES: [[[(u8, u8, u8, u8); K]; W]; H]`. Unused entries are (255, 255, 255, 255).
+    pub fn write_rust<W: Write + ?Sized>(&self, out: &mut W) -> io::Result<()> {
+        let height = self.virtual_to_physical.len();
+        let width = self.virtual_to_physical.first().map_or(0, |row| row.len());
+        writeln!(out, "// Generated by nninterpolation {}, do not edit", env!("CARGO_PKG_VERSION"))?;
+        writeln!(out, "// Layout: {:?}, up to {} rectangles per Virtual Pixel", self.layout, self.max_rectangles)?;
+        writeln!(
+            out, "// {} Physical Pixels redrawn instead of {} with Bounding Boxes\n",
+            self.report.rectangle_pixels, self.report.bounding_box_pixels
+        )?;
+        writeln!(out, "/// (left, top, right, bottom) rectangles of the Physical Pixels for each Virtual Pixel, indexed by [y][x].")?;
+        writeln!(out, "/// Unused entries are (255, 255, 255, 255).")?;
+        writeln!(
+            out, "pub static VIRTUAL_TO_PHYSICAL_RECTANGLES: [[[(u8, u8, u8, u8); {}]; {}]; {}] = [",
+            self.max_rectangles, width, height
+        )?;
+        for row in self.virtual_to_physical.iter() {
+            write!(out, "    [")?;
+            for rectangles in row.iter() {
+                write!(out, "[")?;
+                for &(left, top, right, bottom) in rectangles.iter() {
+                    let rectangle: ByteBoundingBox = (to_u8(left)?, to_u8(top)?, to_u8(right)?, to_u8(bottom)?);
+                    write!(out, "{:?},", rectangle)?;
+                }
+                for _ in rectangles.len()..self.max_rectangles {
+                    write!(out, "{:?},", NO_BOUNDING_BOX)?;
+                }
+                write!(out, "],")?;
+            }
+            writeln!(out, "],")?;
+        }
+        writeln!(out, "];")?;
+        Ok(())
+    }
+}
+
+/// Stack the runs of a footprint into rectangles, then merge them until there are at most `max_rectangles`
+fn cover_footprint(runs: &[BoundingBox], max_rectangles: usize) -> Vec<BoundingBox> {
+    //  Extend a rectangle down when the next row has a run with the same left and right
+    let mut rectangles: Vec<BoundingBox> = Vec::new();
+    for &(left, top, right, bottom) in runs.iter() {
+        match rectangles.iter_mut().find(|r| r.0 == left && r.2 == right && r.3 == top - 1) {
+            Some(rectangle) => rectangle.3 = bottom,
+            None => rectangles.push((left, top, right, bottom)),
+        }
+    }
+
+    //  Merge the pair of rectangles whose Bounding Box adds the fewest Physical Pixels
+    while rectangles.len() > max_rectangles {
+        let mut best = (usize::MAX, 0, 1);
+        for i in 0..rectangles.len() {
+            for j in i + 1..rectangles.len() {
+                let merged = area(&enclose([rectangles[i], rectangles[j]].iter()));
+                let cost = merged.saturating_sub(area(&rectangles[i]) + area(&rectangles[j]));
+                if cost < best.0 { best = (cost, i, j); }
+            }
+        }
+        let (_, i, j) = best;
+        let removed = rectangles.remove(j);
+        rectangles[i] = enclose([rectangles[i], removed].iter());
+    }
+    rectangles
+}
+
+/// Bounding Box of the rectangles
+fn enclose<'a, I: Iterator<Item = &'a BoundingBox>>(rectangles: I) -> BoundingBox {
+    rectangles.fold((i32::MAX, i32::MAX, i32::MIN, i32::MIN), |(left, top, right, bottom), r| {
+        (left.min(r.0), top.min(r.1), right.max(r.2), bottom.max(r.3))
+    })
+}
+
+/// Number of Physical Pixels in a rectangle. The right and bottom edges are inclusive.
+fn area(&(left, top, right, bottom): &BoundingBox) -> usize {
+    ((right - left + 1) * (bottom - top + 1)) as usize
+}
+
+/// Convert a rectangle from indices of `physical_to_virtual` to the coordinates of `virtual_to_physical`,
+/// i.e. Physical (x,y) Coordinates for the quadrant and screen pixels for the full screen.
+/// In the quadrant, index i covers the Physical Pixels from the floor of its scaled edge up to the floor of the
+/// next edge, so neighbouring rectangles neither overlap nor leave gaps. `None` if that covers no Physical Pixel.
+fn to_physical(maps: &PixelMaps, (left, top, right, bottom): BoundingBox) -> Option<BoundingBox> {
+    match maps.layout {
+        MapLayout::Quadrant => {
+            let edge = |x: i32, y: i32| {
+                let pos = maps.geometry.transform_physical_point(cg::Point2::new(x as f64, y as f64));
+                (pos.x.floor() as i32, pos.y.floor() as i32)
+            };
+            let (physical_left, physical_top) = edge(left, top);
+            let (physical_right, physical_bottom) = edge(right + 1, bottom + 1);
+            if physical_right > physical_left && physical_bottom > physical_top {
+                Some((physical_left, physical_top, physical_right - 1, physical_bottom - 1))
+            } else {
+                None
+            }
+        }
+        MapLayout::FullScreen => Some((left, top, right, bottom)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::delaunay_creation::{builtin_control_points, triangulation_from_control_points};
+    use crate::geometry::DisplayGeometry;
+    use crate::interpolation::interpolation_methods::NaturalNeighborInterpolation;
+    use crate::interpolation::Grid;
+    use crate::VirtualAxis;
+
+    #[test]
+    fn rectangles_exactly_cover_each_footprint() {
+        let geometry = DisplayGeometry::default();
+        let delaunay = triangulation_from_control_points(&builtin_control_points());
+        let grid = Grid::from_delaunay_interpolation(&NaturalNeighborInterpolation, &delaunay, &geometry);
+        for &layout in [MapLayout::Quadrant, MapLayout::FullScreen].iter() {
+            let maps = PixelMaps::new(&geometry, grid.values(VirtualAxis::X), grid.values(VirtualAxis::Y), layout);
+            let (physical_width, physical_height) = maps.physical_size();
+
+            //  Index of `physical_to_virtual` that covers each Physical Pixel, from the scaled edges of the indices
+            let indices = |count: usize, edge: &dyn Fn(usize) -> f64| -> Vec<usize> {
+                (0..count).flat_map(|i| (edge(i).floor() as usize..edge(i + 1).floor() as usize).map(move |_| i)).collect()
+            };
+            let (x_indices, y_indices) = match layout {
+                MapLayout::Quadrant => (
+                    indices(physical_width, &|x| geometry.transform_physical_point(cg::Point2::new(x as f64, 0.0)).x),
+                    indices(physical_height, &|y| geometry.transform_physical_point(cg::Point2::new(0.0, y as f64)).y),
+                ),
+                MapLayout::FullScreen => ((0..physical_width).collect(), (0..physical_height).collect()),
+            };
+
+            //  With enough rectangles, every Physical Pixel is covered once, by the rectangles of its Virtual Pixel
+            let cover = RectangleCover::new(&maps, 1000);
+            let mut covered = vec![vec![0; x_indices.len()]; y_indices.len()];
+            for (virtual_y, row) in cover.virtual_to_physical.iter().enumerate() {
+                for (virtual_x, rectangles) in row.iter().enumerate() {
+                    for &(left, top, right, bottom) in rectangles.iter() {
+                        for y in top as usize..=bottom as usize {
+                            for x in left as usize..=right as usize {
+                                let pixel = maps.physical_to_virtual[y_indices[y]][x_indices[x]];
+                                assert_eq!(pixel, (virtual_x as i32, virtual_y as i32), "{:?} ({}, {})", layout, x, y);
+                                covered[y][x] += 1;
+                            }
+                        }
+                    }
+                }
+            }
+            assert!(covered.iter().flat_map(|row| row.iter()).all(|&count| count == 1), "{:?}", layout);
+            let report = cover.report;
+            assert_eq!(report.physical_pixels, x_indices.len() * y_indices.len(), "{:?}", layout);
+            assert_eq!((report.rectangle_pixels, report.exact), (report.physical_pixels, report.virtual_pixels), "{:?}", layout);
+        }
+    }
+}
@@ -0,0 +1,208 @@
+//! Render the interpolated grids as PNG heatmaps, without OpenGL, so that they may be reviewed in CI.
+//! Each grid cell is drawn as a square coloured by its Virtual Coordinate, with the control points as markers
+//! and the edges of the triangulation on top. The colour scale is drawn below the heatmap, with a tick per Virtual Pixel.
+use crate::control_points::VirtualAxis;
+use crate::delaunay_creation::Delaunay;
+use crate::geometry::DisplayGeometry;
+use cgmath as cg;
+use png;
+use spade::HasPosition;
+use std::fs::File;
+use std::io::{self, BufWriter, Write};
+use std::path::Path;
+
+/// RGB colour
+pub type Colour = [u8; 3];
+
+const EDGE_COLOUR: Colour = [255, 255, 255];
+const MARKER_COLOUR: Colour = [255, 0, 0];
+const BACKGROUND_COLOUR: Colour = [0, 0, 0];
+
+/// Colour scale from low to high values, approximating viridis
+const COLOUR_SCALE: [Colour; 5] = [[68, 1, 84], [59, 82, 139], [33, 145, 140], [94, 201, 98], [253, 231, 37]];
+
+/// Height of the colour scale below the heatmap, in image pixels
+const SCALE_HEIGHT: usize = 16;
+
+/// RGB image, indexed by [y][x]
+pub struct Image {
+    pub width: usize,
+    pub height: usize,
+    pub pixels: Vec<Colour>,
+}
+
+impl Image {
+    pub fn new(width: usize, height: usize, colour: Colour) -> Image {
+        Image { width, height, pixels: vec![colour; width * height] }
+    }
+
+    /// Set the pixel at (x,y), ignoring pixels outside the image
+    pub fn set(&mut self, x: i64, y: i64, colour: Colour) {
+        if x >= 0 && y >= 0 && (x as usize) < self.width && (y as usize) < self.height {
+            self.pixels[y as usize * self.width + x as usize] = colour;
+        }
+    }
+
+    pub fn fill_rect(&mut self, left: i64, top: i64, width: i64, height: i64, colour: Colour) {
+        for y in top..top + height {
+            for x in left..left + width {
+                self.set(x, y, colour);
+            }
+        }
+    }
+
+    /// Draw a line with Bresenham's algorithm
+    pub fn draw_line(&mut self, from: (i64, i64), to: (i64, i64), colour: Colour) {
+        let (mut x, mut y) = from;
+        let dx = (to.0 - x).abs();
+        let dy = -(to.1 - y).abs();
+        let step_x = if x < to.0 { 1 } else { -1 };
+        let step_y = if y < to.1 { 1 } else { -1 };
+        let mut error = dx + dy;
+        loop {
+            self.set(x, y, colour);
+            if (x, y) == to { break; }
+            let error2 = 2 * error;
+            if error2 >= dy { error += dy; x += step_x; }
+            if error2 <= dx { error += dx; y += step_y; }
+        }
+    }
+
+    /// Draw a filled circle with an outline, to mark a point
+    pub fn draw_marker(&mut self, centre: (i64, i64), radius: i64, colour: Colour) {
+        for y in -radius - 1..=radius + 1 {
+            for x in -radius - 1..=radius + 1 {
+                let distance = x * x + y * y;
+                if distance <= radius * radius {
+                    self.set(centre.0 + x, centre.1 + y, colour);
+                } else if distance <= (radius + 1) * (radius + 1) {
+                    self.set(centre.0 + x, centre.1 + y, BACKGROUND_COLOUR);
+                }
+            }
+        }
+    }
+
+    /// Write the image as an 8-bit RGB PNG
+    pub fn write_png<W: Write>(&self, out: W) -> io::Result<()> {
+        let mut encoder = png::Encoder::new(out, self.width as u32, self.height as u32);
+        encoder.set_color(png::ColorType::Rgb);
+        encoder.set_depth(png::BitDepth::Eight);
+        let data: Vec<u8> = self.pixels.iter().flat_map(|colour| colour.iter().cloned()).collect();
+        encoder.write_header()
+            .and_then(|mut writer| writer.write_image_data(&data))
+            .map_err(io::Error::other)
+    }
+
+    /// Save the image as a PNG file at `path`
+    pub fn save_png<P: AsRef<Path>>(&self, path: P) -> io::Result<()> {
+        let mut out = BufWriter::new(File::create(path)?);
+        self.write_png(&mut out)?;
+        out.flush()
+    }
+}
+
+/// Return the colour for `value` on the colour scale, from `min` to `max`
+pub fn colour_scale(value: f64, min: f64, max: f64) -> Colour {
+    let position = ((value - min) / (max - min)).clamp(0.0, 1.0) * (COLOUR_SCALE.len() - 1) as f64;
+    let index = (position.floor() as usize).min(COLOUR_SCALE.len() - 2);
+    let fraction = position - index as f64;
+    let (low, high) = (COLOUR_SCALE[index], COLOUR_SCALE[index + 1]);
+    let mut colour = [0; 3];
+    for (channel, value) in colour.iter_mut().enumerate() {
+        *value = (low[channel] as f64 + (high[channel] as f64 - low[channel] as f64) * fraction).round() as u8;
+    }
+    colour
+}
+
+/// Render the grid of Virtual Coordinates on `axis` as a heatmap, `scale` image pixels per grid cell.
+/// If `delaunay` is given, its control points and edges are drawn over the heatmap.
+pub fn render_heatmap<R: AsRef<[f64]>>(
+    geometry: &DisplayGeometry,
+    grid: &[R],
+    axis: VirtualAxis,
+    delaunay: Option<&Delaunay>,
+    scale: usize
+) -> Image {
+    let (min, max) = match axis {
+        VirtualAxis::X => (geometry.x_virtual_min, geometry.x_virtual_max),
+        VirtualAxis::Y => (geometry.y_virtual_min, geometry.y_virtual_max),
+    };
+    let grid_width = grid.first().map_or(0, |row| row.as_ref().len());
+    let grid_height = grid.len();
+    let (width, height) = (grid_width * scale, grid_height * scale);
+    let mut image = Image::new(width, height + SCALE_HEIGHT, BACKGROUND_COLOUR);
+
+    //  Colour each grid cell by its Virtual Coordinate
+    for (y, row) in grid.iter().enumerate() {
+        for (x, &value) in row.as_ref().iter().enumerate() {
+            let colour = colour_scale(value, min, max);
+            image.fill_rect((x * scale) as i64, (y * scale) as i64, scale as i64, scale as i64, colour);
+        }
+    }
+
+    //  Draw the triangulation, converting Physical (x,y) Coordinates to the centre of the grid cells
+    if let Some(delaunay) = delaunay {
+        let to_image = |point: cg::Point2<f64>| {
+            (
+                ((point.x - geometry.x_physical_min) / geometry.x_physical_scale() * scale as f64 + scale as f64 / 2.0).round() as i64,
+                ((point.y - geometry.y_physical_min) / geometry.y_physical_scale() * scale as f64 + scale as f64 / 2.0).round() as i64
+            )
+        };
+        for edge in delaunay.edges() {
+            image.draw_line(to_image(edge.from().position()), to_image(edge.to().position()), EDGE_COLOUR);
+        }
+        let radius = (scale as i64).max(2);
+        for vertex in delaunay.vertices() {
+            image.draw_marker(to_image(vertex.position()), radius, MARKER_COLOUR);
+        }
+    }
+
+    //  Draw the colour scale, with a tick at each Virtual Pixel
+    let increment = match axis {
+        VirtualAxis::X => geometry.x_virtual_increment,
+        VirtualAxis::Y => geometry.y_virtual_increment,
+    };
+    for x in 0..width {
+        let value = min + (max - min) * x as f64 / (width.max(2) - 1) as f64;
+        image.fill_rect(x as i64, (height + 4) as i64, 1, (SCALE_HEIGHT - 4) as i64, colour_scale(value, min, max));
+    }
+    let ticks = ((max - min) / increment).round() as usize;
+    for tick in 0..=ticks {
+        let x = (tick as f64 / ticks.max(1) as f64 * (width.max(1) - 1) as f64).round() as i64;
+        image.fill_rect(x, height as i64, 1, 4, EDGE_COLOUR);
+    }
+    image
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::delaunay_creation::{builtin_control_points, triangulation_from_control_points};
+    use crate::interpolation::interpolation_methods::NaturalNeighborInterpolation;
+    use crate::interpolation::Grid;
+
+    #[test]
+    fn heatmap_has_the_colour_scale_and_markers() {
+        let delaunay = triangulation_from_control_points(&builtin_control_points());
+        let geometry = DisplayGeometry::default();
+        let grid = Grid::from_delaunay_interpolation(&NaturalNeighborInterpolation, &delaunay, &geometry);
+        let scale = 2;
+        let image = render_heatmap(&geometry, grid.values(VirtualAxis::X), VirtualAxis::X, Some(&delaunay), scale);
+
+        let width = (geometry.x_physical_subdivisions() + 1) * scale;
+        let height = (geometry.y_physical_subdivisions() + 1) * scale;
+        assert_eq!((image.width, image.height), (width, height + SCALE_HEIGHT));
+        assert_eq!(image.pixels.len(), image.width * image.height);
+
+        //  The colour scale runs from the lowest to the highest colour along the bottom row
+        let bottom = (image.height - 1) * image.width;
+        assert_eq!(image.pixels[bottom], COLOUR_SCALE[0]);
+        assert_eq!(image.pixels[bottom + width - 1], COLOUR_SCALE[COLOUR_SCALE.len() - 1]);
+
+        //  Each control point is marked at the centre of its grid cell
+        let vertex = delaunay.vertices().next().unwrap().position();
+        let x = ((vertex.x - geometry.x_physical_min) / geometry.x_physical_scale()) as usize * scale + scale / 2;
+        let y = ((vertex.y - geometry.y_physical_min) / geometry.y_physical_scale()) as usize * scale + scale / 2;
+        assert_eq!(image.pixels[y * image.width + x], MARKER_COLOUR);
+    }
+}
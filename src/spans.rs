@@ -0,0 +1,227 @@
+//! Run-length compression of the map of Physical Pixels to Virtual Pixels.
+//! Neighbouring Physical Pixels in a row usually map to the same Virtual Pixel, so each row is stored as spans of
+//! `(run_length, virtual_x, virtual_y)`. A span never crosses rows, so the CHIP-8 Emulator may render row by row.
+//! The spans of all rows are exported as one array, with the offset of the first span of each row.
+use crate::export::{header_lines, physical_to_virtual_u8, MapSource};
+use crate::mapping::PixelMaps;
+use std::io::{self, Write};
+
+/// Run of Physical Pixels in a row that map to the same Virtual (x,y) Pixel
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Span {
+    /// Number of Physical Pixels in the run, from 1 to 255
+    pub run_length: u8,
+    pub virtual_x: u8,
+    pub virtual_y: u8,
+}
+
+/// Sizes of the map of Physical Pixels to Virtual Pixels, before and after compression
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct SpanReport {
+    pub rows: usize,
+    pub physical_pixels: usize,
+    pub spans: usize,
+    /// 2 bytes per Physical Pixel
+    pub uncompressed_bytes: usize,
+    /// 3 bytes per span, plus a 2-byte offset per row to find the start of each row, as exported
+    pub compressed_bytes: usize,
+}
+
+impl SpanReport {
+    /// Compressed size as a fraction of the uncompressed size
+    pub fn ratio(&self) -> f64 {
+        self.compressed_bytes as f64 / self.uncompressed_bytes as f64
+    }
+}
+
+/// Compress each row of the map of Physical Pixels to Virtual Pixels into spans
+pub fn encode_spans(physical_to_virtual: &[Vec<(u8, u8)>]) -> Vec<Vec<Span>> {
+    physical_to_virtual.iter().map(|row| {
+        let mut spans: Vec<Span> = Vec::new();
+        for &(virtual_x, virtual_y) in row.iter() {
+            match spans.last_mut() {
+                //  Extend the last span if it's the same Virtual Pixel and not full
+                Some(span) if span.virtual_x == virtual_x && span.virtual_y == virtual_y && span.run_length < u8::MAX => {
+                    span.run_length += 1;
+                }
+                _ => spans.push(Span { run_length: 1, virtual_x, virtual_y }),
+            }
+        }
+        spans
+    }).collect()
+}
+
+/// Expand the spans back into the map of Physical Pixels to Virtual Pixels
+pub fn decode_spans(rows: &[Vec<Span>]) -> Vec<Vec<(u8, u8)>> {
+    rows.iter().map(|spans| {
+        spans.iter()
+            .flat_map(|span| (0..span.run_length).map(move |_| (span.virtual_x, span.virtual_y)))
+            .collect()
+    }).collect()
+}
+
+/// Compress the map of Physical Pixels to Virtual Pixels, failing if a pixel doesn't fit in a byte
+pub fn spans_from_maps(maps: &PixelMaps) -> io::Result<Vec<Vec<Span>>> {
+    Ok(encode_spans(&physical_to_virtual_u8(maps)?))
+}
+
+/// Compare the sizes of the spans and the map that they were encoded from
+pub fn span_report(rows: &[Vec<Span>]) -> SpanReport {
+    let spans: usize = rows.iter().map(Vec::len).sum();
+    let physical_pixels: usize = rows.iter()
+        .flat_map(|spans| spans.iter())
+        .map(|span| span.run_length as usize)
+        .sum();
+    SpanReport {
+        rows: rows.len(),
+        physical_pixels,
+        spans,
+        uncompressed_bytes: physical_pixels * 2,
+        compressed_bytes: spans * 3 + rows.len() * 2,
+    }
+}
+
+/// Offset of the first span of each row in the spans of all rows, failing if an offset doesn't fit in 16 bits
+pub fn row_offsets(rows: &[Vec<Span>]) -> io::Result<Vec<u16>> {
+    let mut offsets = Vec::new();
+    let mut offset = 0;
+    for spans in rows.iter() {
+        if offset > u16::MAX as usize {
+            return Err(io::Error::new(io::ErrorKind::InvalidData, format!("span offset {} doesn't fit in 16 bits", offset)));
+        }
+        offsets.push(offset as u16);
+        offset += spans.len();
+    }
+    Ok(offsets)
+}
+
+/// Write the compressed map as Rust statics: `PHYSICAL_TO_VIRTUAL_SPANS: [(u8, u8, u8); N]` with
+/// (run_length, virtual_x, virtual_y) spans of all rows, and `PHYSICAL_TO_VIRTUAL_ROWS: [u16; H]` with the offset of
+/// the first span of each row. Row y ends where row y + 1 starts, or at the end of the spans.
+pub fn write_rust_spans<W: Write + ?Sized>(out: &mut W, maps: &PixelMaps, source: &MapSource) -> io::Result<()> {
+    let rows = spans_from_maps(maps)?;
+    let offsets = row_offsets(&rows)?;
+    let report = span_report(&rows);
+
+    for line in header_lines(maps, source) {
+        writeln!(out, "// {}", line)?;
+    }
+    writeln!(out, "// {} Physical Pixels in {} spans, {} bytes instead of {} bytes\n", report.physical_pixels, report.spans, report.compressed_bytes, report.uncompressed_bytes)?;
+
+    writeln!(out, "/// (run_length, virtual_x, virtual_y) spans of PHYSICAL_TO_VIRTUAL_MAP, row by row from y = 0.")?;
+    writeln!(out, "/// Each span maps `run_length` neighbouring Physical Pixels in a row to the same Virtual (x,y) Pixel.")?;
+    writeln!(out, "pub static PHYSICAL_TO_VIRTUAL_SPANS: [(u8, u8, u8); {}] = [", report.spans)?;
+    for spans in rows.iter() {
+        write!(out, "    ")?;
+        for span in spans.iter() {
+            write!(out, "({},{},{}),", span.run_length, span.virtual_x, span.virtual_y)?;
+        }
+        writeln!(out)?;
+    }
+    writeln!(out, "];\n")?;
+
+    writeln!(out, "/// Index of the first span of each Physical row in PHYSICAL_TO_VIRTUAL_SPANS, indexed by [y].")?;
+    writeln!(out, "pub static PHYSICAL_TO_VIRTUAL_ROWS: [u16; {}] = [", offsets.len())?;
+    write_offsets(out, &offsets)?;
+    writeln!(out, "];")?;
+    Ok(())
+}
+
+/// Write the compressed map as a C header with `static const` arrays, include guards and `#define`s for the sizes:
+/// `PHYSICAL_TO_VIRTUAL_SPANS[SPAN_COUNT][3]` holds (run_length, virtual_x, virtual_y) and
+/// `PHYSICAL_TO_VIRTUAL_ROWS[PHYSICAL_HEIGHT]` holds the offset of the first span of each row
+pub fn write_c_spans<W: Write + ?Sized>(out: &mut W, maps: &PixelMaps, source: &MapSource) -> io::Result<()> {
+    let rows = spans_from_maps(maps)?;
+    let offsets = row_offsets(&rows)?;
+    let report = span_report(&rows);
+    let (physical_width, physical_height) = maps.physical_size();
+
+    for line in header_lines(maps, source) {
+        writeln!(out, "// {}", line)?;
+    }
+    writeln!(out, "// {} Physical Pixels in {} spans, {} bytes instead of {} bytes", report.physical_pixels, report.spans, report.compressed_bytes, report.uncompressed_bytes)?;
+    writeln!(out, "#ifndef NNINTERPOLATION_SPANS_H")?;
+    writeln!(out, "#define NNINTERPOLATION_SPANS_H\n")?;
+    writeln!(out, "#include <stdint.h>\n")?;
+
+    writeln!(out, "// Dimensions of the map and number of spans")?;
+    writeln!(out, "#define PHYSICAL_WIDTH  {}", physical_width)?;
+    writeln!(out, "#define PHYSICAL_HEIGHT {}", physical_height)?;
+    writeln!(out, "#define SPAN_COUNT      {}\n", report.spans)?;
+
+    writeln!(out, "// (run_length, virtual_x, virtual_y) spans of PHYSICAL_TO_VIRTUAL_MAP, row by row from y = 0.")?;
+    writeln!(out, "// Each span maps run_length neighbouring Physical Pixels in a row to the same Virtual (x, y) Pixel.")?;
+    writeln!(out, "static const uint8_t PHYSICAL_TO_VIRTUAL_SPANS[SPAN_COUNT][3] = {{")?;
+    for spans in rows.iter() {
+        write!(out, "    ")?;
+        for span in spans.iter() {
+            write!(out, "{{{},{},{}}},", span.run_length, span.virtual_x, span.virtual_y)?;
+        }
+        writeln!(out)?;
+    }
+    writeln!(out, "}};\n")?;
+
+    writeln!(out, "// Index of the first span of each Physical row in PHYSICAL_TO_VIRTUAL_SPANS, indexed by [y].")?;
+    writeln!(out, "// Row y ends where row y + 1 starts, or at SPAN_COUNT.")?;
+    writeln!(out, "static const uint16_t PHYSICAL_TO_VIRTUAL_ROWS[PHYSICAL_HEIGHT] = {{")?;
+    write_offsets(out, &offsets)?;
+    writeln!(out, "}};\n")?;
+    writeln!(out, "#endif  // NNINTERPOLATION_SPANS_H")?;
+    Ok(())
+}
+
+/// Write the row offsets, 16 per line
+fn write_offsets<W: Write + ?Sized>(out: &mut W, offsets: &[u16]) -> io::Result<()> {
+    for chunk in offsets.chunks(16) {
+        write!(out, "    ")?;
+        for offset in chunk.iter() {
+            write!(out, "{},", offset)?;
+        }
+        writeln!(out)?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::delaunay_creation::{builtin_control_points, triangulation_from_control_points};
+    use crate::geometry::DisplayGeometry;
+    use crate::interpolation::Grid;
+    use crate::mapping::MapLayout;
+    use crate::parameters::{MethodParameters, METHOD_NAMES};
+    use crate::VirtualAxis;
+
+    #[test]
+    fn spans_round_trip() {
+        let geometry = DisplayGeometry::default();
+        let delaunay = triangulation_from_control_points(&builtin_control_points());
+        let parameters = MethodParameters::default();
+        for &name in METHOD_NAMES.iter() {
+            let method = parameters.method(name, &delaunay).unwrap();
+            let grid = Grid::from_delaunay_interpolation(&*method, &delaunay, &geometry);
+            for &layout in [MapLayout::Quadrant, MapLayout::FullScreen].iter() {
+                let maps = PixelMaps::new(&geometry, grid.values(VirtualAxis::X), grid.values(VirtualAxis::Y), layout);
+                let context = format!("{} {:?}", name, layout);
+                let map = physical_to_virtual_u8(&maps).expect(&context);
+                let rows = encode_spans(&map);
+                assert_eq!(decode_spans(&rows), map, "{}", context);
+
+                //  Split the exported spans of all rows at the row offsets
+                let spans: Vec<Span> = rows.iter().flat_map(|spans| spans.iter().cloned()).collect();
+                let offsets = row_offsets(&rows).expect(&context);
+                let ends = offsets.iter().skip(1).map(|&offset| offset as usize).chain(Some(spans.len()));
+                let split: Vec<Vec<Span>> = offsets.iter()
+                    .zip(ends)
+                    .map(|(&start, end)| spans[start as usize..end].to_vec())
+                    .collect();
+                assert_eq!(decode_spans(&split), map, "{}", context);
+
+                let report = span_report(&rows);
+                let (physical_width, physical_height) = maps.physical_size();
+                assert_eq!(report.physical_pixels, physical_width * physical_height, "{}", context);
+                assert_eq!(report.compressed_bytes, spans.len() * 3 + offsets.len() * 2, "{}", context);
+            }
+        }
+    }
+}
@@ -0,0 +1,155 @@
+//! Draw the footprint of each Virtual Pixel on the Physical display as an SVG, for design reviews.
+//! Physical Pixels are filled with a colour per Virtual Pixel and the boundaries between Virtual Pixels are outlined.
+//! Each Virtual Pixel is a group `virtual-X-Y` of its footprint and its label at the centre of the footprint,
+//! and its Bounding Box from the map of Virtual Pixels to Physical Pixels is drawn as a dashed outline.
+//! Virtual Pixels without Physical Pixels are listed in the SVG description.
+use crate::mapping::{MapLayout, PixelMaps};
+use std::fmt::Write as FmtWrite;
+use std::io::{self, Write};
+
+/// Write the SVG overlay for the maps, `zoom` SVG units per Physical Pixel
+pub fn write_svg_overlay<W: Write + ?Sized>(out: &mut W, maps: &PixelMaps, zoom: f64) -> io::Result<()> {
+    let (physical_width, physical_height) = maps.physical_size();
+    let (virtual_width, virtual_height) = maps.virtual_size();
+
+    //  Quadrant Bounding Boxes are in Physical (x,y) Coordinates, which are scaled from the grid.
+    //  Full-screen Bounding Boxes are in screen pixels.
+    let (cell_width, cell_height) = match maps.layout {
+        MapLayout::Quadrant => (maps.geometry.x_physical_scale(), maps.geometry.y_physical_scale()),
+        MapLayout::FullScreen => (1.0, 1.0),
+    };
+    let (cell_width, cell_height) = (cell_width * zoom, cell_height * zoom);
+    let width = physical_width as f64 * cell_width;
+    let height = physical_height as f64 * cell_height;
+
+    let mut svg = String::new();
+    writeln!(svg, r#"<svg xmlns="http://www.w3.org/2000/svg" width="{:.0}" height="{:.0}" viewBox="0 0 {:.2} {:.2}">"#,
+             width, height, width, height).unwrap();
+    writeln!(svg, "<title>Footprint of {}x{} Virtual Pixels on {}x{} Physical Pixels ({:?})</title>",
+             virtual_width, virtual_height, physical_width, physical_height, maps.layout).unwrap();
+    let missing: Vec<String> = maps.virtual_to_physical.iter().enumerate()
+        .flat_map(|(y, row)| row.iter().enumerate()
+            .filter(|(_, bounding_box)| bounding_box.is_none())
+            .map(move |(x, _)| format!("({},{})", x, y)))
+        .collect();
+    writeln!(svg, "<desc>Virtual Pixels without Physical Pixels: {}</desc>",
+             if missing.is_empty() { "none".to_string() } else { missing.join(" ") }).unwrap();
+    writeln!(svg, r#"<rect width="100%" height="100%" fill="white"/>"#).unwrap();
+
+    //  Group the footprint of each Virtual Pixel: runs of Physical Pixels in each row that map to it,
+    //  and its label at the centre of the footprint
+    let mut runs = vec![vec![String::new(); virtual_width]; virtual_height];
+    let mut centres = vec![vec![(0.0, 0.0, 0usize); virtual_width]; virtual_height];
+    for (y, row) in maps.physical_to_virtual.iter().enumerate() {
+        let mut start = 0;
+        for x in 1..=row.len() {
+            if x < row.len() && row[x] == row[start] { continue; }
+            let (virtual_x, virtual_y) = (row[start].0 as usize, row[start].1 as usize);
+            if let Some(runs) = runs.get_mut(virtual_y).and_then(|row| row.get_mut(virtual_x)) {
+                writeln!(runs, r#"<rect x="{:.2}" y="{:.2}" width="{:.2}" height="{:.2}"/>"#,
+                         start as f64 * cell_width, y as f64 * cell_height,
+                         (x - start) as f64 * cell_width, cell_height).unwrap();
+            }
+            start = x;
+        }
+        for (x, &(virtual_x, virtual_y)) in row.iter().enumerate() {
+            if let Some(centre) = centres.get_mut(virtual_y as usize).and_then(|row| row.get_mut(virtual_x as usize)) {
+                centre.0 += (x as f64 + 0.5) * cell_width;
+                centre.1 += (y as f64 + 0.5) * cell_height;
+                centre.2 += 1;
+            }
+        }
+    }
+    writeln!(svg, r#"<g stroke="none" font-family="monospace" font-size="{:.2}" text-anchor="middle" dominant-baseline="middle">"#,
+             zoom * 1.5).unwrap();
+    for (virtual_y, row) in centres.iter().enumerate() {
+        for (virtual_x, &(x, y, count)) in row.iter().enumerate() {
+            if count == 0 { continue; }
+            writeln!(svg, r#"<g id="virtual-{}-{}" fill="{}">"#,
+                     virtual_x, virtual_y, fill_colour((virtual_x as i32, virtual_y as i32))).unwrap();
+            svg.push_str(&runs[virtual_y][virtual_x]);
+            writeln!(svg, r#"<text x="{:.2}" y="{:.2}" fill="black">{},{}</text>"#,
+                     x / count as f64, y / count as f64, virtual_x, virtual_y).unwrap();
+            writeln!(svg, "</g>").unwrap();
+        }
+    }
+    writeln!(svg, "</g>").unwrap();
+
+    //  Outline the boundaries between Virtual Pixels
+    let mut path = String::new();
+    for (y, row) in maps.physical_to_virtual.iter().enumerate() {
+        for (x, pixel) in row.iter().enumerate() {
+            let (left, top) = (x as f64 * cell_width, y as f64 * cell_height);
+            if x + 1 < row.len() && row[x + 1] != *pixel {
+                write!(path, "M{:.2} {:.2}v{:.2}", left + cell_width, top, cell_height).unwrap();
+            }
+            if y + 1 < physical_height && maps.physical_to_virtual[y + 1][x] != *pixel {
+                write!(path, "M{:.2} {:.2}h{:.2}", left, top + cell_height, cell_width).unwrap();
+            }
+        }
+    }
+    writeln!(svg, r#"<path d="{}" fill="none" stroke="black" stroke-width="{:.2}"/>"#, path, zoom / 6.0).unwrap();
+
+    //  Draw the Bounding Boxes as dashed outlines. The right and bottom edges are inclusive, so add a cell.
+    writeln!(svg, r#"<g fill="none" stroke="red" stroke-width="{:.2}" stroke-dasharray="{:.2} {:.2}">"#,
+             zoom / 6.0, zoom / 2.0, zoom / 2.0).unwrap();
+    for row in maps.virtual_to_physical.iter() {
+        for &(left, top, right, bottom) in row.iter().flatten() {
+            writeln!(svg, r#"<rect x="{:.2}" y="{:.2}" width="{:.2}" height="{:.2}"/>"#,
+                     left as f64 * zoom, top as f64 * zoom,
+                     (right - left) as f64 * zoom + cell_width, (bottom - top) as f64 * zoom + cell_height).unwrap();
+        }
+    }
+    writeln!(svg, "</g>").unwrap();
+
+    writeln!(svg, "</svg>").unwrap();
+    out.write_all(svg.as_bytes())
+}
+
+/// Light colour for the footprint of a Virtual Pixel, so that neighbouring Virtual Pixels differ
+fn fill_colour((x, y): (i32, i32)) -> String {
+    let hue = (x * 47 + y * 97).rem_euclid(360);
+    let lightness = if (x + y) % 2 == 0 { 80 } else { 90 };
+    format!("hsl({},70%,{}%)", hue, lightness)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::control_points::VirtualAxis;
+    use crate::delaunay_creation::{builtin_control_points, triangulation_from_control_points};
+    use crate::geometry::DisplayGeometry;
+    use crate::interpolation::interpolation_methods::NaturalNeighborInterpolation;
+    use crate::interpolation::Grid;
+
+    #[test]
+    fn overlay_groups_each_virtual_pixel_with_its_label() {
+        let delaunay = triangulation_from_control_points(&builtin_control_points());
+        let geometry = DisplayGeometry::default();
+        let grid = Grid::from_delaunay_interpolation(&NaturalNeighborInterpolation, &delaunay, &geometry);
+        let maps = PixelMaps::new(&geometry, grid.values(VirtualAxis::X), grid.values(VirtualAxis::Y), MapLayout::Quadrant);
+        let mut out = Vec::new();
+        write_svg_overlay(&mut out, &maps, 4.0).unwrap();
+        let svg = String::from_utf8(out).unwrap();
+
+        let mut groups = 0;
+        for (y, row) in maps.virtual_to_physical.iter().enumerate() {
+            for (x, bounding_box) in row.iter().enumerate() {
+                let start = svg.find(&format!(r#"<g id="virtual-{}-{}""#, x, y));
+                assert_eq!(start.is_some(), bounding_box.is_some(), "Virtual Pixel ({},{})", x, y);
+                if let Some(start) = start {
+                    let group = &svg[start..start + svg[start..].find("</g>").unwrap()];
+                    assert!(group.contains("<rect "), "Virtual Pixel ({},{}) has no footprint", x, y);
+                    assert!(group.contains(&format!(">{},{}</text>", x, y)), "Virtual Pixel ({},{}) has no label", x, y);
+                    groups += 1;
+                }
+            }
+        }
+        assert_eq!(svg.matches(r#"<g id="virtual-"#).count(), groups);
+
+        //  The Bounding Boxes are dashed rects
+        let dashed = svg.find("stroke-dasharray").unwrap();
+        let bounding_boxes = &svg[dashed..dashed + svg[dashed..].find("</g>").unwrap()];
+        assert_eq!(bounding_boxes.matches("<rect ").count(), groups);
+    }
+}
@@ -0,0 +1,216 @@
+//! kiss3d demo that renders the triangulation and the interpolated meshes.
+/*
+ * Press h for help.
+ * See ../lib.rs for the interpolation engine.
+ *
+ * *Note*: This demo uses kiss3d which uses an old version of
+ * nalgebra. This nalgebra version is incompatible with spade, that's
+ * the reason why we're using cgmath points in the delaunay triangulation
+ * and nalgebra points for the rendering in kiss3d. Once kiss3d updates,
+ * only nalgebra will be used.
+ */
+use nalgebra as na;
+
+use kiss3d::event::{Action, Key, WindowEvent};
+use kiss3d::light::Light;
+use kiss3d::resource::Mesh;
+use kiss3d::scene::SceneNode;
+use kiss3d::window::Window;
+
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use nninterpolation::delaunay_creation::Delaunay;
+use nninterpolation::{
+    extract_edges, get_normals, get_triangulation_triangles, DisplayGeometry, Grid,
+    InterpolationMethod, VirtualAxis,
+};
+
+struct InterpolationRenderData {
+    edges: Vec<(na::Point3<f32>, na::Point3<f32>)>,
+    mesh: Rc<RefCell<Mesh>>,
+    title: &'static str,
+}
+
+impl InterpolationRenderData {
+    fn new(method: &dyn InterpolationMethod, delaunay: &Delaunay, geometry: &DisplayGeometry, axis: VirtualAxis) -> InterpolationRenderData {
+        println!("{}", method.title());
+        let grid = Grid::from_delaunay_interpolation(method, delaunay, geometry);
+        let (vertices, indices) = grid.get_triangles(axis);
+        let mesh = Mesh::new(vertices, kiss3d_faces(indices), None, None, false);
+        InterpolationRenderData {
+            edges: grid.get_edges(axis),
+            mesh: Rc::new(RefCell::new(mesh)),
+            title: method.title(),
+        }
+    }
+}
+
+fn print_help() {
+    println!("Interpolation Demo");
+    println!("H - print this help");
+    println!("N - show / hide normals");
+    println!("G - switch interpolation method");
+    println!("D - show / hide delaunay triangulation");
+    println!("T - toggle display method of interpolated mesh");
+}
+
+#[derive(PartialEq, Eq)]
+enum DelaunayVisibility {
+    All,
+    OnlyLines,
+    None,
+}
+
+#[derive(PartialEq, Eq)]
+enum GridRenderType {
+    Lines,
+    Polygons,
+}
+
+impl DelaunayVisibility {
+    fn next(&self) -> DelaunayVisibility {
+        use self::DelaunayVisibility::*;
+        match self {
+            All => OnlyLines,
+            OnlyLines => None,
+            None => All,
+        }
+    }
+}
+
+impl GridRenderType {
+    fn next(&self) -> GridRenderType {
+        use self::GridRenderType::*;
+        match self {
+            Lines => Polygons,
+            Polygons => Lines,
+        }
+    }
+}
+
+/// Open the kiss3d window and render until it's closed, with the Virtual Coordinate on `axis` as the height.
+/// G switches between `methods`, e.g. `MethodParameters::all_methods`.
+pub fn run(delaunay: Delaunay, geometry: DisplayGeometry, axis: VirtualAxis, methods: Vec<Box<dyn InterpolationMethod>>) {
+    let mut window = Window::new("Delaunay Demo");
+    window.set_light(Light::StickToCamera);
+
+    print_help();
+
+    let mut delaunay_visibility = DelaunayVisibility::All;
+    let mut grid_render_type = GridRenderType::Lines;
+    let mut show_normals = false;
+
+    // Create the mesh of the delaunay triangulation
+    let delaunay_mesh = create_mesh_from_triangulation(&delaunay, axis);
+    let delaunay_mesh = Rc::new(RefCell::new(delaunay_mesh));
+    let mut delaunay_node = window.add_mesh(delaunay_mesh.clone(), na::Vector3::new(1.0, 1.0, 1.0));
+    delaunay_node.enable_backface_culling(false);
+    let delaunay_lines = extract_edges(&delaunay, axis);
+
+    let interpolation_meshes: Vec<InterpolationRenderData> = methods.iter()
+        .map(|method| InterpolationRenderData::new(&**method, &delaunay, &geometry, axis))
+        .collect();
+
+    let mut cur_interpolation_mesh_node: Option<SceneNode> = None;
+    let mut cur_interpolation_mesh_index = 0;
+
+    let normals = get_normals(&delaunay, axis);
+
+    while window.render() {
+        for event in window.events().iter() {
+            let mut update_interpolation_mesh = false;
+            match event.value {
+                WindowEvent::Key(Key::H, Action::Press, _) => print_help(),
+                WindowEvent::Key(Key::N, Action::Press, _) => show_normals = !show_normals,
+                WindowEvent::Key(Key::G, Action::Press, _) => {
+                    cur_interpolation_mesh_index += 1;
+                    update_interpolation_mesh = true;
+                    if cur_interpolation_mesh_index > interpolation_meshes.len() {
+                        cur_interpolation_mesh_index = 0;
+                    }
+                    if cur_interpolation_mesh_index < interpolation_meshes.len() {
+                        println!(
+                            "Change interpolation method to {}",
+                            interpolation_meshes[cur_interpolation_mesh_index].title
+                        );
+                    }
+                }
+                WindowEvent::Key(Key::T, Action::Press, _) => {
+                    grid_render_type = grid_render_type.next();
+                    update_interpolation_mesh = true;
+                }
+                WindowEvent::Key(Key::D, Action::Press, _) => {
+                    delaunay_visibility = delaunay_visibility.next();
+                    if delaunay_visibility == DelaunayVisibility::All {
+                        delaunay_node = window
+                            .scene_mut()
+                            .add_mesh(delaunay_mesh.clone(), na::Vector3::new(1.0, 1.0, 1.));
+                        delaunay_node.enable_backface_culling(false);
+                    } else {
+                        delaunay_node.unlink();
+                    }
+                }
+                _ => {}
+            }
+            if update_interpolation_mesh {
+                if let Some(mut mesh_node) = cur_interpolation_mesh_node {
+                    mesh_node.unlink();
+                    cur_interpolation_mesh_node = None;
+                }
+                if cur_interpolation_mesh_index < interpolation_meshes.len()
+                    && grid_render_type == GridRenderType::Polygons
+                {
+                    let mut new_node = window.scene_mut().add_mesh(
+                        interpolation_meshes[cur_interpolation_mesh_index]
+                            .mesh
+                            .clone(),
+                        na::Vector3::new(1.0, 1.0, 1.0),
+                    );
+                    new_node.enable_backface_culling(false);
+                    cur_interpolation_mesh_node = Some(new_node);
+                }
+            }
+        }
+
+        if delaunay_visibility == DelaunayVisibility::All
+            || delaunay_visibility == DelaunayVisibility::OnlyLines
+        {
+            let color = na::Point3::new(0.8, 0.5, 0.2);
+            for &(from, to) in &delaunay_lines {
+                window.draw_line(&from, &to, &color);
+            }
+        }
+
+        if grid_render_type == GridRenderType::Lines {
+            if let Some(mesh) = interpolation_meshes.get(cur_interpolation_mesh_index) {
+                let color = na::Point3::new(0.5, 0.8, 0.2);
+                for &(from, to) in &mesh.edges {
+                    window.draw_line(&from, &to, &color);
+                }
+            }
+        }
+
+        if show_normals {
+            let color = na::Point3::new(0.5, 0.5, 1.0);
+            for &(from, to) in &normals {
+                window.draw_line(&from, &to, &color);
+            }
+        }
+    }
+}
+
+fn create_mesh_from_triangulation(delaunay: &Delaunay, axis: VirtualAxis) -> Mesh {
+    let (coords, faces) = get_triangulation_triangles(delaunay, axis);
+    Mesh::new(coords, kiss3d_faces(faces), None, None, false)
+}
+
+/// kiss3d meshes have 16-bit indices, so exit if the mesh has more than 65536 vertices
+fn kiss3d_faces(faces: Vec<na::Point3<u32>>) -> Vec<na::Point3<u16>> {
+    let max_index = faces.iter().flat_map(|face| face.iter()).max().cloned().unwrap_or(0);
+    if max_index > u32::from(u16::MAX) {
+        eprintln!("mesh has {} vertices, but the viewer can only show 65536; use a coarser geometry", max_index + 1);
+        std::process::exit(1);
+    }
+    faces.iter().map(|face| na::Point3::new(face.x as u16, face.y as u16, face.z as u16)).collect()
+}